@@ -0,0 +1,678 @@
+use crate::format::ConfigFormat;
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use toml_edit::{ImDocument, Item, Table, TableLike, Value};
+
+pub const KNOWN_KEYS: &[&str] = &[
+    "build_path",
+    "use_union_types_for_export",
+    "use_union_types_for_parameters",
+    "assign_static_strings",
+    "sort",
+    "exclude_deprecated_from_lists",
+    "value_type",
+    "include",
+    "enums",
+    "migrations",
+    "naming",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+// Where a diagnostic points to in its file, along with the line it is on
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub width: usize,
+}
+
+impl Location {
+    pub fn new(content: &str, span: Range<usize>) -> Self {
+        let start = span.start.min(content.len());
+        let line_start = content[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = content[start..]
+            .find('\n')
+            .map(|i| start + i)
+            .unwrap_or(content.len());
+        let snippet = content[line_start..line_end].trim_end_matches('\r');
+        let column = content[line_start..start].chars().count();
+        let width = content[start..span.end.clamp(start, line_end)]
+            .chars()
+            .count()
+            .max(1);
+
+        Location {
+            line: content[..line_start].matches('\n').count() + 1,
+            column: column + 1,
+            snippet: snippet.to_string(),
+            width,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub path: PathBuf,
+    // Unknown for problems that cannot be traced back to a span of the file
+    pub location: Option<Location>,
+    pub hint: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: Severity,
+        message: String,
+        path: &Path,
+        content: &str,
+        span: Option<Range<usize>>,
+    ) -> Self {
+        Diagnostic {
+            severity,
+            message,
+            path: path.to_path_buf(),
+            location: span.map(|span| Location::new(content, span)),
+            hint: None,
+        }
+    }
+
+    pub fn error(message: String, path: &Path, content: &str, span: Option<Range<usize>>) -> Self {
+        Diagnostic::new(Severity::Error, message, path, content, span)
+    }
+
+    // Spans of config errors can only be found in TOML files, as the other formats lose them when parsed
+    pub fn from_config_error(
+        error: &ConfigError,
+        path: &Path,
+        content: &str,
+        format: ConfigFormat,
+    ) -> Self {
+        let span = match format {
+            ConfigFormat::Toml => find_toml_span(content, &error.keys, error.is_key),
+            _ => None,
+        };
+        Diagnostic {
+            hint: error.hint.clone(),
+            ..Diagnostic::new(error.severity, error.message.clone(), path, content, span)
+        }
+    }
+
+    pub fn with_hint(mut self, hint: String) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.severity, self.message)?;
+        let Some(location) = &self.location else {
+            write!(f, " --> {}", self.path.display())?;
+            if let Some(hint) = &self.hint {
+                write!(f, "\n  = hint: {}", hint)?;
+            }
+            return Ok(());
+        };
+        let gutter = " ".repeat(location.line.to_string().len());
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter,
+            self.path.display(),
+            location.line,
+            location.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", location.line, location.snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(location.column - 1),
+            "^".repeat(location.width)
+        )?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n{} = hint: {}", gutter, hint)?;
+        }
+        Ok(())
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if a_char == *b_char {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(row[j + 1])
+            };
+            previous = current;
+        }
+    }
+    row[b_chars.len()]
+}
+
+fn suggest<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(input, candidate), *candidate))
+        .filter(|(distance, candidate)| *distance <= candidate.len().max(3) / 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+// Suggests the closest candidate, if any is close enough to be a typo
+pub(crate) fn get_suggestion_hint(input: &str, candidates: &[&str]) -> Option<String> {
+    suggest(input, candidates).map(|candidate| format!("did you mean `{}`?", candidate))
+}
+
+// Suggests the closest candidate, or lists every candidate if none is close
+pub(crate) fn get_candidates_hint(input: &str, candidates: &[&str]) -> String {
+    get_suggestion_hint(input, candidates)
+        .unwrap_or_else(|| format!("expected one of: {}", candidates.join(", ")))
+}
+
+// A key of a table, or the position of a value in an array
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConfigKey {
+    Name(String),
+    Index(usize),
+}
+
+impl From<&str> for ConfigKey {
+    fn from(name: &str) -> Self {
+        ConfigKey::Name(name.to_string())
+    }
+}
+
+impl From<usize> for ConfigKey {
+    fn from(index: usize) -> Self {
+        ConfigKey::Index(index)
+    }
+}
+
+impl fmt::Display for ConfigKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigKey::Name(name) => write!(f, "{}", name),
+            ConfigKey::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+pub fn join_keys(keys: &[ConfigKey], key: impl Into<ConfigKey>) -> Vec<ConfigKey> {
+    let mut keys = keys.to_vec();
+    keys.push(key.into());
+    keys
+}
+
+// A problem found while building the model, located by the keys leading to it from the root of the
+// config so that it can be traced back to whichever file and format the value was read from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConfigError {
+    pub severity: Severity,
+    pub message: String,
+    pub keys: Vec<ConfigKey>,
+    // Points at the last key rather than at its value, e.g. for unknown keys
+    pub is_key: bool,
+    pub hint: Option<String>,
+}
+
+impl ConfigError {
+    pub fn new(message: String, keys: &[ConfigKey]) -> Self {
+        ConfigError {
+            severity: Severity::Error,
+            message,
+            keys: keys.to_vec(),
+            is_key: false,
+            hint: None,
+        }
+    }
+
+    pub fn warning(message: String, keys: &[ConfigKey]) -> Self {
+        ConfigError {
+            severity: Severity::Warning,
+            ..ConfigError::new(message, keys)
+        }
+    }
+
+    pub fn at_key(mut self) -> Self {
+        self.is_key = true;
+        self
+    }
+
+    pub fn with_hint(mut self, hint: String) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+pub fn has_errors(errors: &[ConfigError]) -> bool {
+    errors.iter().any(|error| error.severity == Severity::Error)
+}
+
+// Joins every error into a single message, for callers that only take a string
+pub fn join_errors(errors: &[ConfigError]) -> String {
+    errors
+        .iter()
+        .filter(|error| error.severity == Severity::Error)
+        .map(|error| error.message.as_str())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+pub(crate) fn unknown_key(
+    key: &str,
+    keys: &[ConfigKey],
+    location: &str,
+    candidates: &[&str],
+) -> ConfigError {
+    ConfigError::new(format!("{} has unknown key '{}'", location, key), keys)
+        .at_key()
+        .with_hint(get_candidates_hint(key, candidates))
+}
+
+// A value of a TOML document, which may sit in a table, an inline table or an array
+#[derive(Clone, Copy)]
+enum TomlNode<'a> {
+    Item(&'a Item),
+    Value(&'a Value),
+    Table(&'a Table),
+}
+
+impl<'a> TomlNode<'a> {
+    fn as_table_like(self) -> Option<&'a dyn TableLike> {
+        match self {
+            TomlNode::Item(item) => item.as_table_like(),
+            TomlNode::Value(value) => value.as_inline_table().map(|table| table as &dyn TableLike),
+            TomlNode::Table(table) => Some(table),
+        }
+    }
+
+    fn span(self) -> Option<Range<usize>> {
+        match self {
+            TomlNode::Item(item) => item.span(),
+            TomlNode::Value(value) => value.span(),
+            TomlNode::Table(table) => table.span(),
+        }
+    }
+
+    // The node under `key`, along with the span of the key itself
+    fn get(self, key: &ConfigKey) -> Option<(TomlNode<'a>, Option<Range<usize>>)> {
+        match key {
+            ConfigKey::Name(name) => {
+                let table = self.as_table_like()?;
+                let item = table.get(name)?;
+                Some((TomlNode::Item(item), table.key(name).and_then(|k| k.span())))
+            }
+            ConfigKey::Index(index) => {
+                let node = match self {
+                    TomlNode::Item(Item::ArrayOfTables(tables)) => {
+                        TomlNode::Table(tables.get(*index)?)
+                    }
+                    TomlNode::Item(Item::Value(Value::Array(values)))
+                    | TomlNode::Value(Value::Array(values)) => TomlNode::Value(values.get(*index)?),
+                    _ => return None,
+                };
+                Some((node, None))
+            }
+        }
+    }
+}
+
+// Follows the keys of an error through a TOML document, down to the span of the key or value at
+// fault. Keys the document does not have, such as those of items it inherits, stop at the last one found
+pub fn find_toml_span(content: &str, keys: &[ConfigKey], is_key: bool) -> Option<Range<usize>> {
+    let document = ImDocument::parse(content).ok()?;
+    let mut node = TomlNode::Item(document.as_item());
+    let mut key_span = None;
+    let mut value_span = None;
+    for key in keys {
+        let Some((child, child_key_span)) = node.get(key) else {
+            break;
+        };
+        node = child;
+        key_span = child_key_span;
+        value_span = child.span();
+    }
+    if is_key {
+        key_span.or(value_span)
+    } else {
+        value_span.or(key_span)
+    }
+}
+
+// Syntax errors of a TOML document, which come with a span unlike those of the other formats
+pub(crate) fn check_toml_syntax(path: &Path, content: &str) -> Option<Diagnostic> {
+    ImDocument::parse(content).err().map(|error| {
+        Diagnostic::error(
+            error.message().trim().to_string(),
+            path,
+            content,
+            error.span(),
+        )
+    })
+}
+
+#[cfg(test)]
+pub mod diagnostic_test {
+    use super::*;
+    use crate::include::load_config;
+
+    const HEADER: &str = r#"use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+"#;
+
+    fn check(body: &str) -> Vec<Diagnostic> {
+        let content = format!("{}{}", HEADER, body);
+        match load_config(Path::new("pseudo-enum.toml"), &content, ConfigFormat::Toml) {
+            Ok((_, warnings)) => warnings,
+            Err(diagnostics) => diagnostics,
+        }
+    }
+
+    fn get_line_column(diagnostic: &Diagnostic) -> Option<(usize, usize)> {
+        diagnostic
+            .location
+            .as_ref()
+            .map(|location| (location.line, location.column))
+    }
+
+    #[test]
+    fn report_every_problem() {
+        let diagnostics = check(
+            r#"sortt = "alphabetical"
+[enums]
+MapType = ["City", "City", 3]
+[enums.Device]
+Phone = 1
+Tablet = 1
+Console = -1
+Pc = 70000
+"#,
+        );
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "The config has unknown key 'sortt'",
+                "Item #3 of 'MapType' needs to be a name or a table, found integer",
+                "Enum 'MapType' has more than one item named 'City'",
+                "Item 'Device.Console' has value -1, which does not fit in u16",
+                "Items 'Device.Phone' and 'Device.Tablet' share value 1",
+                "Item 'Device.Pc' has value 70000, which does not fit in u16",
+            ]
+        );
+        assert_eq!(diagnostics[0].hint.as_deref(), Some("did you mean `sort`?"));
+        assert_eq!(get_line_column(&diagnostics[2]), Some((6, 20)));
+    }
+
+    #[test]
+    fn report_item_metadata_problems() {
+        let diagnostics = check(
+            r#"[enums.MapType.City]
+display = 3
+tag = ["Urban"]
+[enums.MapType.PowerLab]
+value = 2
+tags = "Science"
+"#,
+        );
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Item 'MapType.City' needs a string display, found integer",
+                "Item 'MapType.City' has unknown key 'tag'",
+                "Item 'MapType.City' is missing a value",
+                "Item 'MapType.PowerLab' needs an array of string tags",
+            ]
+        );
+        assert_eq!(diagnostics[1].hint.as_deref(), Some("did you mean `tags`?"));
+    }
+
+    #[test]
+    fn check_detailed_enums() {
+        let path = Path::new("pseudo-enum.toml");
+        assert!(load_config(
+            path,
+            crate::model::config_test::PAYLOAD_STR,
+            ConfigFormat::Toml
+        )
+        .is_ok());
+
+        let diagnostics = check(
+            r#"[enums.ToolClassId]
+items = ["BladeCutlass"]
+field = { Damage = "number" }
+"#,
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Enum 'ToolClassId' has unknown key 'field'"
+        );
+        assert_eq!(
+            diagnostics[0].hint.as_deref(),
+            Some("did you mean `fields`?")
+        );
+    }
+
+    #[test]
+    fn check_parent_enums() {
+        let diagnostics = check(
+            r#"[enums]
+EquipContext = ["Right", "Left"]
+MainHand = { subset_of = "EquipContex", items = ["Right"] }
+"#,
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Enum 'MainHand' refers to unknown enum 'EquipContex'"
+        );
+        assert_eq!(
+            diagnostics[0].hint.as_deref(),
+            Some("did you mean `EquipContext`?")
+        );
+    }
+
+    #[test]
+    fn check_default_items() {
+        let diagnostics = check(
+            r#"[enums]
+MapType = { default = "Citty", items = ["City", { name = "PowerLab", value = 5 }] }
+BigMapType = { extends = "MapType", default = "City", items = ["Desert"] }
+"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Default 'Citty' of 'MapType' is not one of its items"
+        );
+        assert_eq!(diagnostics[0].hint.as_deref(), Some("did you mean `City`?"));
+    }
+
+    #[test]
+    fn check_empty_enums() {
+        let diagnostics = check("[enums]\nMapType = []\n");
+        assert_eq!(diagnostics[0].message, "Enum 'MapType' has no items");
+        assert_eq!(get_line_column(&diagnostics[0]), Some((5, 11)));
+
+        let diagnostics = check("[enums]\n");
+        assert_eq!(diagnostics[0].message, "The config has no enums");
+        assert_eq!(get_line_column(&diagnostics[0]), Some((4, 2)));
+    }
+
+    #[test]
+    fn check_reserved_values() {
+        let diagnostics = check(
+            r#"[enums]
+MapType = { reserved = [3, "7-9"], reserved_names = [1], items = ["City"] }
+"#,
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Enum 'MapType' has invalid reserved value \"7-9\""
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "Enum 'MapType' needs string reserved names, found integer"
+        );
+    }
+
+    #[test]
+    fn check_migration_tables() {
+        let diagnostics = check(
+            r#"[enums]
+MapType = ["City", "PowerLab"]
+
+[migrations.MapTyp]
+Town = "City"
+
+[migrations.MapType]
+Lab = 2
+"#,
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Migrations refer to unknown enum 'MapTyp'"
+        );
+        assert_eq!(
+            diagnostics[0].hint.as_deref(),
+            Some("did you mean `MapType`?")
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "Migration 'Lab' of 'MapType' needs the name of an item, found integer"
+        );
+    }
+
+    #[test]
+    fn check_naming_conventions() {
+        let diagnostics = check(
+            r#"naming = { items = "Pascalcase", keep_orignal = true }
+[enums]
+MapType = ["City"]
+"#,
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Unknown naming convention \"Pascalcase\" for items"
+        );
+        assert_eq!(
+            diagnostics[0].hint.as_deref(),
+            Some("did you mean `PascalCase`?")
+        );
+        assert_eq!(
+            diagnostics[1].message,
+            "Setting 'naming' has unknown key 'keep_orignal'"
+        );
+    }
+
+    #[test]
+    fn check_flag_values() {
+        let diagnostics = check(
+            r#"[enums.Permission]
+kind = "flags"
+items = { Read = 1, Write = 3 }
+"#,
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Flag 'Permission.Write' has value 3, which is not a single bit"
+        );
+        assert_eq!(get_line_column(&diagnostics[0]), Some((6, 29)));
+    }
+
+    #[test]
+    fn check_string_values() {
+        let diagnostics = check(
+            r#"[enums.AnalyticsEvent]
+Purchase = "purchase_v2"
+Refund = 2
+Restore = "purchase_v2"
+"#,
+        );
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Items 'AnalyticsEvent.Purchase' and 'AnalyticsEvent.Restore' share value \"purchase_v2\"",
+                "Item 'AnalyticsEvent.Refund' needs a string value, as 'AnalyticsEvent' is a string enum",
+            ]
+        );
+    }
+
+    #[test]
+    fn warn_about_deprecated_items() {
+        let diagnostics = check(
+            r#"[enums.MapType]
+City = 1
+PowerLab = { value = 2, deprecated = "use City instead" }
+"#,
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].message,
+            "Item 'MapType.PowerLab' is deprecated"
+        );
+        assert_eq!(diagnostics[0].hint.as_deref(), Some("use City instead"));
+    }
+
+    #[test]
+    fn render_snippet() {
+        let diagnostics = check("[enums]\nMapType = [\"City\", \"City\"]\n");
+        assert_eq!(
+            diagnostics[0].to_string(),
+            r#"error: Enum 'MapType' has more than one item named 'City'
+ --> pseudo-enum.toml:5:20
+  |
+5 | MapType = ["City", "City"]
+  |                    ^^^^^^
+  = hint: `City` is already item #1"#
+        );
+    }
+
+    #[test]
+    fn render_without_location() {
+        let diagnostic = Diagnostic::error(
+            "Default 'Town' of 'MapType' is not one of its items".to_string(),
+            Path::new("pseudo-enum.json"),
+            "{}",
+            None,
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: Default 'Town' of 'MapType' is not one of its items\n --> pseudo-enum.json"
+        );
+    }
+}
@@ -1,25 +1,73 @@
-use crate::model::{Enum, EnumSetConfig};
+use crate::model::{get_locale_ids, Enum, EnumKind, EnumSetConfig, LuauName, MigrationKey};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 use stylua_lib::{
     self, CallParenType, CollapseSimpleStatement, Config, IndentType, LineEndings,
     OutputVerification, QuoteStyle, SortRequiresConfig,
 };
 
+pub fn to_luau_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            // other control characters would end up raw in the source
+            c if c.is_control() => out.push_str(&format!("\\u{{{:04x}}}", c as u32)),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn to_doc_comment(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("--- {}", line.trim_end()))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct EnumTypeDefinition {
     pub value: Enum,
     pub is_exported: bool,
+    pub is_union: bool,
 }
 
 impl fmt::Display for EnumTypeDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut union_str = String::new();
-        for (i, item) in self.value.items.iter().enumerate() {
-            if i > 0 {
-                union_str.push_str(" | ");
+        if self.is_union {
+            for (i, item) in self.value.items.iter().enumerate() {
+                if i > 0 {
+                    union_str.push_str(" | ");
+                }
+                union_str.push_str(&format!("\"{}\"", item.name));
+            }
+        } else {
+            union_str.push_str("string");
+        }
+        if self.value.has_documentation() {
+            for item in self.value.items.iter() {
+                let mut line = format!("`{}`", item.name);
+                if let Some(display) = &item.display {
+                    line.push_str(&format!(" ({})", display));
+                }
+                if let Some(description) = &item.description {
+                    line.push_str(&format!(": {}", description));
+                }
+                match item.deprecated.as_deref() {
+                    Some("") => line.push_str(" (deprecated)"),
+                    Some(reason) => line.push_str(&format!(" (deprecated: {})", reason)),
+                    None => {}
+                }
+                writeln!(f, "{}", to_doc_comment(&line))?;
             }
-            union_str.push_str(&format!("\"{}\"", item.name));
         }
         if self.is_exported {
             write!(f, "export type {} = {}", self.value.name, union_str)
@@ -32,20 +80,26 @@ impl fmt::Display for EnumTypeDefinition {
 pub struct EnumListDefinition {
     pub value: Enum,
     pub is_frozen: bool,
+    pub is_deprecated_excluded: bool,
 }
 
 impl EnumListDefinition {
     pub fn get_variable_name(&self) -> String {
-        return format!("{}List", self.value.name);
+        format!("{}List", self.value.name)
     }
 }
 
 impl fmt::Display for EnumListDefinition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut union_str = String::new();
-        for (i, item) in self.value.items.iter().enumerate() {
+        let items = self
+            .value
+            .items
+            .iter()
+            .filter(|item| !(self.is_deprecated_excluded && item.deprecated.is_some()));
+        for (i, item) in items.enumerate() {
             if i > 0 {
-                union_str.push_str(",");
+                union_str.push(',');
             }
             union_str.push_str(&format!("\"{}\"", item.name));
         }
@@ -71,7 +125,7 @@ pub struct EnumDictDefinition {
 
 impl EnumDictDefinition {
     pub fn get_variable_name(&self) -> String {
-        return format!("{}Dict", self.value.name);
+        format!("{}Dict", self.value.name)
     }
 }
 
@@ -82,19 +136,33 @@ impl fmt::Display for EnumDictDefinition {
             if i > 0 {
                 union_str.push_str(",\n");
             } else {
-                union_str.push_str("\n");
+                union_str.push('\n');
             }
-            if self.assign_as_static_string {
+            if let Some(description) = &item.description {
+                union_str.push_str(&format!("{}\n", to_doc_comment(description)));
+            }
+            if let Some(reason) = &item.deprecated {
                 union_str.push_str(&format!(
-                    "\t{}=\"{}\" :: {}",
-                    item.name,
-                    item.name,
-                    format!("\"{}\"", item.name)
+                    "{}\n",
+                    to_doc_comment(format!("@deprecated {}", reason).trim_end())
                 ));
+            }
+            let item_type = if self.assign_as_static_string {
+                format!("\"{}\"", item.name)
             } else {
+                self.value.name.to_string()
+            };
+            union_str.push_str(&format!(
+                "\t{}=\"{}\" :: {}",
+                item.name, item.name, item_type
+            ));
+            for alias in item.aliases.iter() {
                 union_str.push_str(&format!(
-                    "\t{}=\"{}\" :: {}",
-                    item.name, item.name, self.value.name
+                    ",\n{}\n\t{}=\"{}\" :: {}",
+                    to_doc_comment(&format!("Alias of `{}`.", item.name)),
+                    alias,
+                    item.name,
+                    item_type
                 ));
             }
         }
@@ -124,7 +192,7 @@ pub struct EnumValueDictDefinition {
 
 impl EnumValueDictDefinition {
     pub fn get_variable_name(&self) -> String {
-        return format!("{}ValueDict", self.value.name);
+        format!("{}ValueDict", self.value.name)
     }
 }
 
@@ -135,9 +203,12 @@ impl fmt::Display for EnumValueDictDefinition {
             if i > 0 {
                 union_str.push_str(",\n");
             } else {
-                union_str.push_str("\n");
+                union_str.push('\n');
+            }
+            union_str.push_str(&format!("\t{}={}", item.name, item.get_luau_value()));
+            for alias in item.aliases.iter() {
+                union_str.push_str(&format!(",\n\t{}={}", alias, item.get_luau_value()));
             }
-            union_str.push_str(&format!("\t{}={}", item.name, item.value,));
         }
         if self.is_frozen {
             write!(
@@ -166,7 +237,7 @@ pub struct EnumInverseValueDictDefinition {
 
 impl EnumInverseValueDictDefinition {
     pub fn get_variable_name(&self) -> String {
-        return format!("{}InverseValueDict", self.value.name);
+        format!("{}InverseValueDict", self.value.name)
     }
 }
 
@@ -177,14 +248,16 @@ impl fmt::Display for EnumInverseValueDictDefinition {
             if i > 0 {
                 union_str.push_str(",\n");
             } else {
-                union_str.push_str("\n");
+                union_str.push('\n');
             }
             if self.assign_as_static_string {
-                union_str.push_str(&format!("\t[{}]=\"{}\"", item.value, item.name));
+                union_str.push_str(&format!("\t[{}]=\"{}\"", item.get_luau_value(), item.name));
             } else {
                 union_str.push_str(&format!(
                     "\t[{}]=\"{}\" :: {}",
-                    item.value, item.name, self.value.name
+                    item.get_luau_value(),
+                    item.name,
+                    self.value.name
                 ));
             }
         }
@@ -206,8 +279,271 @@ impl fmt::Display for EnumInverseValueDictDefinition {
     }
 }
 
+// The items sorted by value, which the ordering helpers walk through
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EnumOrderDefinition {
+    pub value: Enum,
+    pub is_frozen: bool,
+}
+
+impl EnumOrderDefinition {
+    pub fn get_variable_name(&self) -> String {
+        format!("{}Order", self.value.name)
+    }
+}
+
+impl fmt::Display for EnumOrderDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self
+            .value
+            .get_ordered_items()
+            .iter()
+            .map(|item| format!("\"{}\"", item.name))
+            .collect();
+        if self.is_frozen {
+            write!(
+                f,
+                "local {} = table.freeze({{{}}})",
+                self.get_variable_name(),
+                names.join(",")
+            )
+        } else {
+            write!(
+                f,
+                "local {} = {{{}}}",
+                self.get_variable_name(),
+                names.join(",")
+            )
+        }
+    }
+}
+
+// The position of each item, and of each alias, in the order
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EnumIndexDefinition {
+    pub value: Enum,
+    pub is_frozen: bool,
+}
+
+impl EnumIndexDefinition {
+    pub fn get_variable_name(&self) -> String {
+        format!("{}Index", self.value.name)
+    }
+}
+
+impl fmt::Display for EnumIndexDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut union_str: String = String::new();
+        for (i, item) in self.value.get_ordered_items().iter().enumerate() {
+            union_str.push_str(&format!("\n\t{}={},", item.name, i + 1));
+            for alias in item.aliases.iter() {
+                union_str.push_str(&format!("\n\t{}={},", alias, i + 1));
+            }
+        }
+        if self.is_frozen {
+            write!(
+                f,
+                "local {} = table.freeze({{{}\n}})",
+                self.get_variable_name(),
+                union_str
+            )
+        } else {
+            write!(
+                f,
+                "local {} = {{{}\n}}",
+                self.get_variable_name(),
+                union_str
+            )
+        }
+    }
+}
+
+// Old names and values of saved data, mapped to the items they became
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EnumMigrationDefinition {
+    pub value: Enum,
+    pub is_frozen: bool,
+}
+
+impl EnumMigrationDefinition {
+    pub fn get_variable_name(&self) -> String {
+        format!("{}Migrations", self.value.name)
+    }
+}
+
+impl fmt::Display for EnumMigrationDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut union_str: String = String::new();
+        for migration in self.value.migrations.iter() {
+            let key = match &migration.from {
+                MigrationKey::Value(value) => format!("[{}]", value),
+                MigrationKey::Name(name) if LuauName::new(name).is_ok() => name.clone(),
+                MigrationKey::Name(name) => format!("[{}]", to_luau_string(name)),
+            };
+            union_str.push_str(&format!("\n\t{} = \"{}\",", key, migration.to));
+        }
+        if self.is_frozen {
+            write!(
+                f,
+                "local {}: {{ [any]: {} }} = table.freeze({{{}\n}})",
+                self.get_variable_name(),
+                self.value.name,
+                union_str
+            )
+        } else {
+            write!(
+                f,
+                "local {}: {{ [any]: {} }} = {{{}\n}}",
+                self.get_variable_name(),
+                self.value.name,
+                union_str
+            )
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EnumMetadataDefinition {
+    pub value: Enum,
+    pub is_frozen: bool,
+}
+
+impl EnumMetadataDefinition {
+    pub fn get_variable_name(&self) -> String {
+        format!("{}Metadata", self.value.name)
+    }
+}
+
+impl fmt::Display for EnumMetadataDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut union_str: String = String::new();
+        for item in self.value.items.iter().filter(|item| item.has_metadata()) {
+            let mut fields = Vec::new();
+            if let Some(display) = &item.display {
+                fields.push(format!("display = {}", to_luau_string(display)));
+            }
+            if let Some(description) = &item.description {
+                fields.push(format!("description = {}", to_luau_string(description)));
+            }
+            if !item.tags.is_empty() {
+                let tags: Vec<String> = item
+                    .tags
+                    .iter()
+                    .map(|tag| format!("[{}] = true", to_luau_string(tag)))
+                    .collect();
+                fields.push(format!("tags = table.freeze({{{}}})", tags.join(", ")));
+            }
+            union_str.push_str(&format!(
+                "\n\t{} = table.freeze({{{}}}),",
+                item.name,
+                fields.join(", ")
+            ));
+        }
+        if self.is_frozen {
+            write!(
+                f,
+                "local {}: {{ [string]: ItemMetadata }} = table.freeze({{{}\n}})",
+                self.get_variable_name(),
+                union_str
+            )
+        } else {
+            write!(
+                f,
+                "local {}: {{ [string]: ItemMetadata }} = {{{}\n}}",
+                self.get_variable_name(),
+                union_str
+            )
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EnumDataTypeDefinition {
+    pub value: Enum,
+    pub is_exported: bool,
+}
+
+impl fmt::Display for EnumDataTypeDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<String> = self
+            .value
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name, field.field_type))
+            .collect();
+        if self.is_exported {
+            write!(
+                f,
+                "export type {} = {{ {} }}",
+                self.value.get_data_type_name(),
+                fields.join(", ")
+            )
+        } else {
+            write!(
+                f,
+                "type {} = {{ {} }}",
+                self.value.get_data_type_name(),
+                fields.join(", ")
+            )
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct EnumDataDefinition {
+    pub value: Enum,
+    pub is_frozen: bool,
+}
+
+impl EnumDataDefinition {
+    pub fn get_variable_name(&self) -> String {
+        format!("{}DataDict", self.value.name)
+    }
+}
+
+impl fmt::Display for EnumDataDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut union_str: String = String::new();
+        for item in self.value.items.iter() {
+            let values: Vec<String> = self
+                .value
+                .fields
+                .iter()
+                .filter_map(|field| {
+                    item.data.get(field.name.as_str()).map(|value| {
+                        format!("{} = {}", field.name, value.to_luau(&field.field_type))
+                    })
+                })
+                .collect();
+            union_str.push_str(&format!(
+                "\n\t{} = table.freeze({{ {} }}),",
+                item.name,
+                values.join(", ")
+            ));
+        }
+        let annotation = format!("{{ [string]: {} }}", self.value.get_data_type_name());
+        if self.is_frozen {
+            write!(
+                f,
+                "local {}: {} = table.freeze({{{}\n}})",
+                self.get_variable_name(),
+                annotation,
+                union_str
+            )
+        } else {
+            write!(
+                f,
+                "local {}: {} = {{{}\n}}",
+                self.get_variable_name(),
+                annotation,
+                union_str
+            )
+        }
+    }
+}
+
 #[allow(deprecated)]
-fn format_code(code: String) -> String {
+fn format_code(code: String) -> Result<String, String> {
     let style_result: Result<String, stylua_lib::Error> = stylua_lib::format_code(
         &code,
         Config {
@@ -227,102 +563,576 @@ fn format_code(code: String) -> String {
         OutputVerification::None,
     );
 
-    let fmt_content = match style_result {
-        Ok(out) => out,
-        Err(error) => {
-            panic!("Problem styling code: {}, \n{}", error, code)
-        }
-    };
-
-    return fmt_content;
-    // return code
+    style_result.map_err(|error| format!("Problem styling code: {}", error))
 }
 
-pub fn dump(config: EnumSetConfig) -> String {
-    let mut header = String::new();
-    header.push_str("--!strict");
-    header.push_str("\n-- DO NOT EDIT MANUALLY!! This file was generated by nightcycle/pseudo-enum, edits will likely be overwritten!");
+pub const TREE_NAMES: &[&str] = &[
+    "listTree",
+    "valueTree",
+    "invValueTree",
+    "orderTree",
+    "indexTree",
+];
+pub const HELPER_NAMES: &[&str] = &[
+    "getEnumItems",
+    "getEnumItemFromValue",
+    "getValueFromEnumItem",
+    "isEnumItem",
+    "compare",
+    "next",
+    "previous",
+    "first",
+    "last",
+    "count",
+    "indexOf",
+];
+pub const METADATA_TREE_NAME: &str = "metadataTree";
+pub const METADATA_TYPE_NAME: &str = "ItemMetadata";
+pub const METADATA_HELPER_NAMES: &[&str] = &["getDisplayName", "getDescription", "hasTag"];
+pub const DATA_TREE_NAME: &str = "dataTree";
+pub const DATA_HELPER_NAMES: &[&str] = &["getData"];
+pub const FLAG_TREE_NAME: &str = "flagTree";
+pub const FLAG_HELPER_NAMES: &[&str] = &[
+    "hasFlag",
+    "addFlag",
+    "removeFlag",
+    "flagsToList",
+    "flagsFromList",
+];
+pub const PARENT_TREE_NAME: &str = "parentTree";
+pub const PARENT_HELPER_NAMES: &[&str] = &["toParent", "fromParent"];
+pub const DEFAULT_TREE_NAME: &str = "defaultTree";
+pub const DEFAULT_HELPER_NAMES: &[&str] = &[
+    "getDefault",
+    "getEnumItemFromValueOrDefault",
+    "getValueFromEnumItemOrDefault",
+];
+pub const MIGRATION_TREE_NAME: &str = "migrationTree";
+pub const MIGRATION_HELPER_NAMES: &[&str] = &["migrate"];
+pub const RETIRED_TREE_NAME: &str = "retiredTree";
+pub const RETIRED_HELPER_NAMES: &[&str] = &["isRetiredValue"];
+pub const LOCALIZATION_TREE_NAME: &str = "localizationTree";
+pub const LOCALIZATION_HELPER_NAMES: &[&str] = &["getLocalizedName"];
+pub const TYPE_NAMES: &[&str] = &[
+    "EnumName", "any", "boolean", "buffer", "never", "nil", "number", "string", "thread",
+    "unknown", "vector",
+];
 
-    for e in config.enums.iter() {
-        header.push_str(&format!(
-            "\n{}",
-            EnumTypeDefinition {
-                value: e.clone(),
-                is_exported: true,
-            }
-        ));
-        header.push_str(&format!(
-            "\n{}",
-            EnumListDefinition {
-                value: e.clone(),
-                is_frozen: true,
-            }
-        ));
-        header.push_str(&format!(
-            "\n{}",
-            EnumDictDefinition {
-                value: e.clone(),
-                is_frozen: true,
-                assign_as_static_string: config.assign_static_strings
-            }
-        ));
-        header.push_str(&format!(
-            "\n{}",
-            EnumValueDictDefinition {
-                value: e.clone(),
-                is_frozen: true
-            }
-        ));
-        header.push_str(&format!(
-            "\n{}",
-            EnumInverseValueDictDefinition {
+fn get_local_names(e: &Enum) -> Vec<String> {
+    let mut local_names = vec![
+        EnumListDefinition {
+            value: e.clone(),
+            is_frozen: true,
+            is_deprecated_excluded: false,
+        }
+        .get_variable_name(),
+        EnumDictDefinition {
+            value: e.clone(),
+            is_frozen: true,
+            assign_as_static_string: true,
+        }
+        .get_variable_name(),
+        EnumValueDictDefinition {
+            value: e.clone(),
+            is_frozen: true,
+        }
+        .get_variable_name(),
+        EnumInverseValueDictDefinition {
+            value: e.clone(),
+            is_frozen: true,
+            assign_as_static_string: true,
+        }
+        .get_variable_name(),
+        EnumOrderDefinition {
+            value: e.clone(),
+            is_frozen: true,
+        }
+        .get_variable_name(),
+        EnumIndexDefinition {
+            value: e.clone(),
+            is_frozen: true,
+        }
+        .get_variable_name(),
+    ];
+    if e.has_metadata() {
+        local_names.push(
+            EnumMetadataDefinition {
                 value: e.clone(),
                 is_frozen: true,
-                assign_as_static_string: config.assign_static_strings
             }
-        ));
+            .get_variable_name(),
+        );
     }
-
-    let mut list_tree = String::new();
-    list_tree.push_str("\nlocal listTree = {");
-    for e in config.enums.iter() {
-        list_tree.push_str(&format!(
-            "\n\t{} = {},",
-            e.name,
-            EnumListDefinition {
+    if !e.migrations.is_empty() {
+        local_names.push(
+            EnumMigrationDefinition {
                 value: e.clone(),
                 is_frozen: true,
             }
             .get_variable_name(),
-        ))
+        );
     }
-    list_tree.push_str("\n}");
-    list_tree.push_str("\ntable.freeze(listTree)");
-
-    let mut value_tree = String::new();
-    value_tree.push_str("\nlocal valueTree = {");
-    for e in config.enums.iter() {
-        value_tree.push_str(&format!(
-            "\n\t{} = {},",
-            e.name,
-            EnumValueDictDefinition {
+    if !e.fields.is_empty() {
+        local_names.push(
+            EnumDataDefinition {
                 value: e.clone(),
                 is_frozen: true,
             }
             .get_variable_name(),
-        ))
+        );
     }
-    value_tree.push_str("\n}");
-    value_tree.push_str("\ntable.freeze(valueTree)");
+    local_names
+}
 
-    let mut inv_value_tree = String::new();
-    inv_value_tree.push_str("\nlocal invValueTree = {");
-    for e in config.enums.iter() {
-        inv_value_tree.push_str(&format!(
-            "\n\t{} = {},",
-            e.name,
-            EnumInverseValueDictDefinition {
-                value: e.clone(),
+fn get_type_names(e: &Enum) -> Vec<String> {
+    let mut type_names = vec![e.name.to_string()];
+    if !e.fields.is_empty() {
+        type_names.push(e.get_data_type_name());
+    }
+    type_names
+}
+
+fn get_reserved_locals(enums: &[Enum]) -> Vec<&'static str> {
+    let mut names = TREE_NAMES.to_vec();
+    if enums.iter().any(|e| e.has_metadata()) {
+        names.push(METADATA_TREE_NAME);
+    }
+    if enums.iter().any(|e| !e.fields.is_empty()) {
+        names.push(DATA_TREE_NAME);
+    }
+    if enums.iter().any(|e| e.kind == EnumKind::Flags) {
+        names.push(FLAG_TREE_NAME);
+    }
+    if enums.iter().any(|e| e.parent.is_some()) {
+        names.push(PARENT_TREE_NAME);
+    }
+    if enums.iter().any(|e| e.default.is_some()) {
+        names.push(DEFAULT_TREE_NAME);
+    }
+    if enums.iter().any(|e| !e.reserved.is_empty()) {
+        names.push(RETIRED_TREE_NAME);
+    }
+    if enums.iter().any(|e| !e.migrations.is_empty()) {
+        names.push(MIGRATION_TREE_NAME);
+    }
+    if enums.iter().any(|e| e.has_text()) {
+        names.push(LOCALIZATION_TREE_NAME);
+    }
+    names
+}
+
+fn get_reserved_types(enums: &[Enum]) -> Vec<&'static str> {
+    let mut names = TYPE_NAMES.to_vec();
+    if enums.iter().any(|e| e.has_metadata()) {
+        names.push(METADATA_TYPE_NAME);
+    }
+    names
+}
+
+fn get_reserved_fields(enums: &[Enum]) -> Vec<&'static str> {
+    let mut names = HELPER_NAMES.to_vec();
+    if enums.iter().any(|e| e.has_metadata()) {
+        names.extend_from_slice(METADATA_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| !e.fields.is_empty()) {
+        names.extend_from_slice(DATA_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| e.kind == EnumKind::Flags) {
+        names.extend_from_slice(FLAG_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| e.parent.is_some()) {
+        names.extend_from_slice(PARENT_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| e.default.is_some()) {
+        names.extend_from_slice(DEFAULT_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| !e.reserved.is_empty()) {
+        names.extend_from_slice(RETIRED_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| !e.migrations.is_empty()) {
+        names.extend_from_slice(MIGRATION_HELPER_NAMES);
+    }
+    if enums.iter().any(|e| e.has_text()) {
+        names.extend_from_slice(LOCALIZATION_HELPER_NAMES);
+    }
+    names
+}
+
+fn claim_identifier(
+    claimed: &mut HashMap<String, String>,
+    errors: &mut Vec<String>,
+    kind: &str,
+    identifier: String,
+    owner: String,
+) {
+    match claimed.get(&identifier) {
+        Some(existing) => errors.push(format!(
+            "{} generates {} '{}', which clashes with {}",
+            owner, kind, identifier, existing
+        )),
+        None => {
+            claimed.insert(identifier, owner);
+        }
+    }
+}
+
+pub fn check_identifiers(enums: &[Enum]) -> Result<(), String> {
+    let mut errors = Vec::new();
+    let mut locals: HashMap<String, String> = HashMap::new();
+    let mut types: HashMap<String, String> = HashMap::new();
+    let mut fields: HashMap<String, String> = HashMap::new();
+    for name in get_reserved_locals(enums) {
+        locals.insert(name.to_string(), format!("the generated local '{}'", name));
+    }
+    for name in get_reserved_types(enums) {
+        types.insert(name.to_string(), format!("the type '{}'", name));
+    }
+    for name in get_reserved_fields(enums) {
+        fields.insert(name.to_string(), format!("the helper function '{}'", name));
+    }
+
+    for e in enums.iter() {
+        let owner = format!("enum '{}'", e.name);
+        for local_name in get_local_names(e) {
+            claim_identifier(&mut locals, &mut errors, "local", local_name, owner.clone());
+        }
+        for type_name in get_type_names(e) {
+            claim_identifier(&mut types, &mut errors, "type", type_name, owner.clone());
+        }
+        let path = e.get_path();
+        if path.len() == 1 {
+            claim_identifier(&mut fields, &mut errors, "field", e.name.to_string(), owner);
+        } else {
+            // enums sharing a namespace share its field
+            let namespace = format!("namespace '{}'", path[0]);
+            if fields.get(path[0].as_str()) != Some(&namespace) {
+                claim_identifier(
+                    &mut fields,
+                    &mut errors,
+                    "field",
+                    path[0].to_string(),
+                    namespace,
+                );
+            }
+        }
+        for other in enums.iter() {
+            let other_path = other.get_path();
+            if other_path.len() > path.len() && other_path.starts_with(&path) {
+                errors.push(format!(
+                    "enum '{}' is also used as a namespace by enum '{}'",
+                    e.get_full_name(),
+                    other.get_full_name()
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+fn get_overload_type(enums: &[Enum], get_signature: impl Fn(&Enum) -> String) -> String {
+    let mut overload_type = String::new();
+    overload_type.push('(');
+    for (i, e) in enums.iter().enumerate() {
+        if i > 0 {
+            overload_type.push_str(" & ");
+        }
+        overload_type.push_str(&format!("({})", get_signature(e)));
+    }
+    overload_type.push(')');
+    overload_type
+}
+
+// The type of values accepted by helpers shared between number and string enums
+fn get_luau_value_type(enums: &[Enum]) -> String {
+    let mut value_types: Vec<&str> = Vec::new();
+    for e in enums.iter() {
+        let value_type = e.kind.get_luau_type();
+        if !value_types.contains(&value_type) {
+            value_types.push(value_type);
+        }
+    }
+    value_types.sort();
+    if value_types.is_empty() {
+        "number".to_string()
+    } else {
+        value_types.join(" | ")
+    }
+}
+
+fn get_function_end(use_overloads: bool, overload_type: &str) -> String {
+    if use_overloads {
+        format!("\n\tend :: {},", overload_type)
+    } else {
+        "\n\tend,".to_string()
+    }
+}
+
+// Locale ids with a region, such as `pt-br`, are not valid identifiers
+fn get_locale_key(locale_id: &str) -> String {
+    if locale_id.contains('-') {
+        format!("[{}]", to_luau_string(locale_id))
+    } else {
+        locale_id.to_string()
+    }
+}
+
+// Trees are keyed by the dotted name, which needs brackets once it contains a dot
+fn get_tree_key(e: &Enum) -> String {
+    if e.path.is_empty() {
+        e.name.to_string()
+    } else {
+        format!("[\"{}\"]", e.get_full_name())
+    }
+}
+
+// Nests the dicts of namespaced enums into frozen tables, keeping declaration order
+fn push_namespace(interface: &mut String, entries: &[(Vec<LuauName>, String)], depth: usize) {
+    let indent = "\t".repeat(depth);
+    let mut segments: Vec<&LuauName> = Vec::new();
+    for (path, _) in entries.iter() {
+        if !segments.contains(&&path[0]) {
+            segments.push(&path[0]);
+        }
+    }
+    for segment in segments {
+        for (path, variable_name) in entries
+            .iter()
+            .filter(|(path, _)| path.len() == 1 && path[0] == *segment)
+        {
+            interface.push_str(&format!("\n{}{} = {},", indent, path[0], variable_name));
+        }
+        let children: Vec<(Vec<LuauName>, String)> = entries
+            .iter()
+            .filter(|(path, _)| path.len() > 1 && path[0] == *segment)
+            .map(|(path, variable_name)| (path[1..].to_vec(), variable_name.clone()))
+            .collect();
+        if !children.is_empty() {
+            interface.push_str(&format!("\n{}{} = table.freeze({{", indent, segment));
+            push_namespace(interface, &children, depth + 1);
+            interface.push_str(&format!("\n{}}}),", indent));
+        }
+    }
+}
+
+fn push_metadata_lookup(interface: &mut String) {
+    interface.push_str("\n\t\tlocal dict = valueTree[enumName]");
+    interface.push_str("\n\t\tassert(dict, `invalid enumName: \"{enumName}\"`)");
+    interface.push_str("\n\t\tassert(dict[name], `invalid value: \"{enumName}\" -> \"{name}\"`)");
+    // aliases share the metadata of their canonical item
+    interface.push_str("\n\t\tlocal item = invValueTree[enumName][dict[name]]");
+    interface.push_str(&format!(
+        "\n\t\tlocal metadata = if {}[enumName] then {}[enumName][item] else nil",
+        METADATA_TREE_NAME, METADATA_TREE_NAME
+    ));
+}
+
+// Looks up the order of the enum and the index of `name` in it
+fn push_order_lookup(interface: &mut String, name: &str) {
+    interface.push_str("\n\t\tlocal order = orderTree[enumName]");
+    interface.push_str("\n\t\tassert(order, `invalid enumName: \"{enumName}\"`)");
+    interface.push_str(&format!(
+        "\n\t\tlocal {}Index = indexTree[enumName][{}]",
+        name, name
+    ));
+    interface.push_str(&format!(
+        "\n\t\tassert({}Index, `invalid value: \"{{enumName}}\" -> \"{{{}}}\"`)",
+        name, name
+    ));
+}
+
+fn push_default_lookup(interface: &mut String) {
+    interface.push_str(&format!(
+        "\n\t\tlocal default = {}[enumName]",
+        DEFAULT_TREE_NAME
+    ));
+    interface.push_str("\n\t\tassert(default, `enum has no default: \"{enumName}\"`)");
+}
+
+fn push_flag_lookup(interface: &mut String) {
+    interface.push_str(&format!("\n\t\tlocal dict = {}[enumName]", FLAG_TREE_NAME));
+    interface.push_str("\n\t\tassert(dict, `enum is not a flag enum: \"{enumName}\"`)");
+    interface.push_str("\n\t\tlocal value = dict[name]");
+    interface.push_str("\n\t\tassert(value, `invalid value: \"{enumName}\" -> \"{name}\"`)");
+}
+
+pub fn dump(config: EnumSetConfig) -> Result<String, String> {
+    let enums = config.get_sorted_enums();
+    check_identifiers(&enums)?;
+    let has_metadata = enums.iter().any(|e| e.has_metadata());
+    let data_enums: Vec<Enum> = enums
+        .iter()
+        .filter(|e| !e.fields.is_empty())
+        .cloned()
+        .collect();
+    let flag_enums: Vec<Enum> = enums
+        .iter()
+        .filter(|e| e.kind == EnumKind::Flags)
+        .cloned()
+        .collect();
+    let child_enums: Vec<Enum> = enums
+        .iter()
+        .filter(|e| e.parent.is_some())
+        .cloned()
+        .collect();
+    let has_retired_values = enums.iter().any(|e| !e.reserved.is_empty());
+    let migration_enums: Vec<Enum> = enums
+        .iter()
+        .filter(|e| !e.migrations.is_empty())
+        .cloned()
+        .collect();
+    let text_enums: Vec<Enum> = enums.iter().filter(|e| e.has_text()).cloned().collect();
+    let default_enums: Vec<Enum> = enums
+        .iter()
+        .filter(|e| e.default.is_some())
+        .cloned()
+        .collect();
+
+    let mut header = String::new();
+    header.push_str("--!strict");
+    header.push_str("\n-- DO NOT EDIT MANUALLY!! This file was generated by nightcycle/pseudo-enum, edits will likely be overwritten!");
+    if has_metadata {
+        header.push_str(&format!(
+            "\ntype {} = {{ display: string?, description: string?, tags: {{ [string]: boolean }}? }}",
+            METADATA_TYPE_NAME
+        ));
+    }
+
+    for e in enums.iter() {
+        header.push_str(&format!(
+            "\n{}",
+            EnumTypeDefinition {
+                value: e.clone(),
+                is_exported: true,
+                is_union: config.use_union_types_for_export,
+            }
+        ));
+        header.push_str(&format!(
+            "\n{}",
+            EnumListDefinition {
+                value: e.clone(),
+                is_frozen: true,
+                is_deprecated_excluded: config.exclude_deprecated_from_lists,
+            }
+        ));
+        header.push_str(&format!(
+            "\n{}",
+            EnumDictDefinition {
+                value: e.clone(),
+                is_frozen: true,
+                assign_as_static_string: config.assign_static_strings
+            }
+        ));
+        header.push_str(&format!(
+            "\n{}",
+            EnumValueDictDefinition {
+                value: e.clone(),
+                is_frozen: true
+            }
+        ));
+        header.push_str(&format!(
+            "\n{}",
+            EnumInverseValueDictDefinition {
+                value: e.clone(),
+                is_frozen: true,
+                assign_as_static_string: config.assign_static_strings
+            }
+        ));
+        header.push_str(&format!(
+            "\n{}",
+            EnumOrderDefinition {
+                value: e.clone(),
+                is_frozen: true,
+            }
+        ));
+        header.push_str(&format!(
+            "\n{}",
+            EnumIndexDefinition {
+                value: e.clone(),
+                is_frozen: true,
+            }
+        ));
+        if !e.migrations.is_empty() {
+            header.push_str(&format!(
+                "\n{}",
+                EnumMigrationDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+            ));
+        }
+        if e.has_metadata() {
+            header.push_str(&format!(
+                "\n{}",
+                EnumMetadataDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+            ));
+        }
+        if !e.fields.is_empty() {
+            header.push_str(&format!(
+                "\n{}",
+                EnumDataTypeDefinition {
+                    value: e.clone(),
+                    is_exported: true,
+                }
+            ));
+            header.push_str(&format!(
+                "\n{}",
+                EnumDataDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+            ));
+        }
+    }
+
+    let mut list_tree = String::new();
+    list_tree.push_str("\nlocal listTree = {");
+    for e in enums.iter() {
+        list_tree.push_str(&format!(
+            "\n\t{} = {},",
+            get_tree_key(e),
+            EnumListDefinition {
+                value: e.clone(),
+                is_frozen: true,
+                is_deprecated_excluded: config.exclude_deprecated_from_lists,
+            }
+            .get_variable_name(),
+        ))
+    }
+    list_tree.push_str("\n}");
+    list_tree.push_str("\ntable.freeze(listTree)");
+
+    let mut value_tree = String::new();
+    value_tree.push_str("\nlocal valueTree = {");
+    for e in enums.iter() {
+        value_tree.push_str(&format!(
+            "\n\t{} = {},",
+            get_tree_key(e),
+            EnumValueDictDefinition {
+                value: e.clone(),
+                is_frozen: true,
+            }
+            .get_variable_name(),
+        ))
+    }
+    value_tree.push_str("\n}");
+    value_tree.push_str("\ntable.freeze(valueTree)");
+
+    let mut inv_value_tree = String::new();
+    inv_value_tree.push_str("\nlocal invValueTree = {");
+    for e in enums.iter() {
+        inv_value_tree.push_str(&format!(
+            "\n\t{} = {},",
+            get_tree_key(e),
+            EnumInverseValueDictDefinition {
+                value: e.clone(),
                 is_frozen: true,
                 assign_as_static_string: config.assign_static_strings
             }
@@ -332,99 +1142,753 @@ pub fn dump(config: EnumSetConfig) -> String {
     inv_value_tree.push_str("\n}");
     inv_value_tree.push_str("\ntable.freeze(invValueTree)");
 
-    let mut enum_name_type = String::new();
-    {
-        enum_name_type.push_str("\nexport type EnumName = ");
-        for (i, e) in config.enums.iter().enumerate() {
-            if i > 0 {
-                enum_name_type.push_str(" | ");
+    let mut order_tree = String::new();
+    order_tree.push_str("\nlocal orderTree = {");
+    for e in enums.iter() {
+        order_tree.push_str(&format!(
+            "\n\t{} = {},",
+            get_tree_key(e),
+            EnumOrderDefinition {
+                value: e.clone(),
+                is_frozen: true,
             }
-            enum_name_type.push_str(&format!("\"{}\"", e.name));
+            .get_variable_name(),
+        ))
+    }
+    order_tree.push_str("\n}");
+    order_tree.push_str("\ntable.freeze(orderTree)");
+
+    let mut index_tree = String::new();
+    index_tree.push_str("\nlocal indexTree = {");
+    for e in enums.iter() {
+        index_tree.push_str(&format!(
+            "\n\t{} = {},",
+            get_tree_key(e),
+            EnumIndexDefinition {
+                value: e.clone(),
+                is_frozen: true,
+            }
+            .get_variable_name(),
+        ))
+    }
+    index_tree.push_str("\n}");
+    index_tree.push_str("\ntable.freeze(indexTree)");
+
+    let mut metadata_tree = String::new();
+    if has_metadata {
+        metadata_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: {{ [string]: {} }} }} = {{",
+            METADATA_TREE_NAME, METADATA_TYPE_NAME
+        ));
+        for e in enums.iter().filter(|e| e.has_metadata()) {
+            metadata_tree.push_str(&format!(
+                "\n\t{} = {},",
+                get_tree_key(e),
+                EnumMetadataDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+                .get_variable_name(),
+            ))
         }
+        metadata_tree.push_str("\n}");
+        metadata_tree.push_str(&format!("\ntable.freeze({})", METADATA_TREE_NAME));
     }
 
-    let mut get_name_union_type = String::new();
-    {
-        get_name_union_type.push_str("(");
-        for (i, e) in config.enums.iter().enumerate() {
-            if i > 0 {
-                get_name_union_type.push_str(" & ");
+    let mut data_tree = String::new();
+    if !data_enums.is_empty() {
+        data_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: {{ [string]: any }} }} = {{",
+            DATA_TREE_NAME
+        ));
+        for e in data_enums.iter() {
+            data_tree.push_str(&format!(
+                "\n\t{} = {},",
+                get_tree_key(e),
+                EnumDataDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+                .get_variable_name(),
+            ))
+        }
+        data_tree.push_str("\n}");
+        data_tree.push_str(&format!("\ntable.freeze({})", DATA_TREE_NAME));
+    }
+
+    let mut flag_tree = String::new();
+    if !flag_enums.is_empty() {
+        flag_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: {{ [string]: number }} }} = {{",
+            FLAG_TREE_NAME
+        ));
+        for e in flag_enums.iter() {
+            flag_tree.push_str(&format!(
+                "\n\t{} = {},",
+                get_tree_key(e),
+                EnumValueDictDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+                .get_variable_name(),
+            ))
+        }
+        flag_tree.push_str("\n}");
+        flag_tree.push_str(&format!("\ntable.freeze({})", FLAG_TREE_NAME));
+    }
+
+    let mut parent_tree = String::new();
+    if !child_enums.is_empty() {
+        parent_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: string }} = {{",
+            PARENT_TREE_NAME
+        ));
+        for e in child_enums.iter() {
+            if let Some(parent) = enums
+                .iter()
+                .find(|other| Some(&other.name) == e.parent.as_ref().map(|parent| &parent.name))
+            {
+                parent_tree.push_str(&format!(
+                    "\n\t{} = \"{}\",",
+                    get_tree_key(e),
+                    parent.get_full_name()
+                ));
             }
-            get_name_union_type.push_str(&format!("((\"{}\", number) -> {})", e.name, e.name));
         }
-        get_name_union_type.push_str(")");
+        parent_tree.push_str("\n}");
+        parent_tree.push_str(&format!("\ntable.freeze({})", PARENT_TREE_NAME));
     }
 
-    let mut get_value_union_type = String::new();
-    {
-        get_value_union_type.push_str("(");
-        for (i, e) in config.enums.iter().enumerate() {
-            if i > 0 {
-                get_value_union_type.push_str(" & ");
+    let mut default_tree = String::new();
+    if !default_enums.is_empty() {
+        default_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: string }} = {{",
+            DEFAULT_TREE_NAME
+        ));
+        for e in default_enums.iter() {
+            if let Some(default) = &e.default {
+                default_tree.push_str(&format!("\n\t{} = \"{}\",", get_tree_key(e), default));
+            }
+        }
+        default_tree.push_str("\n}");
+        default_tree.push_str(&format!("\ntable.freeze({})", DEFAULT_TREE_NAME));
+    }
+
+    let mut retired_tree = String::new();
+    if has_retired_values {
+        retired_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: {{ {{ number }} }} }} = {{",
+            RETIRED_TREE_NAME
+        ));
+        for e in enums.iter().filter(|e| !e.reserved.is_empty()) {
+            let ranges: Vec<String> = e
+                .reserved
+                .iter()
+                .map(|range| format!("{{ {}, {} }}", range.start(), range.end()))
+                .collect();
+            retired_tree.push_str(&format!(
+                "\n\t{} = table.freeze({{ {} }}),",
+                get_tree_key(e),
+                ranges.join(", ")
+            ));
+        }
+        retired_tree.push_str("\n}");
+        retired_tree.push_str(&format!("\ntable.freeze({})", RETIRED_TREE_NAME));
+    }
+
+    let mut migration_tree = String::new();
+    if !migration_enums.is_empty() {
+        migration_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: {{ [any]: string }} }} = {{",
+            MIGRATION_TREE_NAME
+        ));
+        for e in migration_enums.iter() {
+            migration_tree.push_str(&format!(
+                "\n\t{} = {},",
+                get_tree_key(e),
+                EnumMigrationDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                }
+                .get_variable_name(),
+            ));
+        }
+        migration_tree.push_str("\n}");
+        migration_tree.push_str(&format!("\ntable.freeze({})", MIGRATION_TREE_NAME));
+    }
+
+    let mut localization_tree = String::new();
+    if !text_enums.is_empty() {
+        localization_tree.push_str(&format!(
+            "\nlocal {}: {{ [string]: {{ [string]: {{ [string]: string }} }} }} = {{",
+            LOCALIZATION_TREE_NAME
+        ));
+        for locale_id in get_locale_ids(&text_enums) {
+            localization_tree.push_str(&format!(
+                "\n\t{} = table.freeze({{",
+                get_locale_key(&locale_id)
+            ));
+            for e in text_enums.iter() {
+                let texts: Vec<String> = e
+                    .items
+                    .iter()
+                    .filter_map(|item| {
+                        item.text
+                            .get(&locale_id)
+                            .map(|text| format!("{} = {}", item.name, to_luau_string(text)))
+                    })
+                    .collect();
+                if !texts.is_empty() {
+                    localization_tree.push_str(&format!(
+                        "\n\t\t{} = table.freeze({{ {} }}),",
+                        get_tree_key(e),
+                        texts.join(", ")
+                    ));
+                }
             }
-            get_value_union_type.push_str(&format!("((\"{}\", {}) -> number)", e.name, e.name));
+            localization_tree.push_str("\n\t}),");
         }
-        get_value_union_type.push_str(")");
+        localization_tree.push_str("\n}");
+        localization_tree.push_str(&format!("\ntable.freeze({})", LOCALIZATION_TREE_NAME));
     }
 
-    let mut get_items_union_type = String::new();
+    let mut enum_name_type = String::new();
     {
-        get_items_union_type.push_str("(");
-        for (i, e) in config.enums.iter().enumerate() {
+        enum_name_type.push_str("\nexport type EnumName = ");
+        for (i, e) in enums.iter().enumerate() {
             if i > 0 {
-                get_items_union_type.push_str(" & ");
+                enum_name_type.push_str(" | ");
             }
-            get_items_union_type.push_str(&format!("((\"{}\") -> {{{}}})", e.name, e.name));
+            enum_name_type.push_str(&format!("\"{}\"", e.get_full_name()));
         }
-        get_items_union_type.push_str(")");
     }
 
+    let use_overloads = config.use_union_types_for_parameters;
+
+    let value_type = get_luau_value_type(&enums);
+    let get_name_union_type = get_overload_type(&enums, |e| {
+        format!(
+            "(\"{}\", {}) -> {}",
+            e.get_full_name(),
+            e.kind.get_luau_type(),
+            e.name
+        )
+    });
+    let get_value_union_type = get_overload_type(&enums, |e| {
+        format!(
+            "(\"{}\", {}) -> {}",
+            e.get_full_name(),
+            e.name,
+            e.kind.get_luau_type()
+        )
+    });
+    let get_items_union_type = get_overload_type(&enums, |e| {
+        format!("(\"{}\") -> {{{}}}", e.get_full_name(), e.name)
+    });
+
     let mut interface = String::new();
     interface.push_str("\nreturn {");
-    interface.push_str("\n\tgetEnumItems = function(enumName: EnumName)");
+    if use_overloads {
+        interface.push_str("\n\tgetEnumItems = function(enumName: EnumName)");
+    } else {
+        interface.push_str("\n\tgetEnumItems = function(enumName: EnumName): { string }");
+    }
     interface.push_str("\n\t\tlocal list = listTree[enumName]");
     interface.push_str("\n\t\tassert(list, `invalid enumName: \"{enumName}\"`)");
     interface.push_str("\n\t\treturn list");
-    interface.push_str(&format!("\n\tend :: {},", get_items_union_type));
-    interface.push_str("\n\tgetEnumItemFromValue = function(enumName: EnumName, value: number)");
+    interface.push_str(&get_function_end(use_overloads, &get_items_union_type));
+    if use_overloads {
+        interface.push_str(&format!(
+            "\n\tgetEnumItemFromValue = function(enumName: EnumName, value: {})",
+            value_type
+        ));
+    } else {
+        interface.push_str(&format!(
+            "\n\tgetEnumItemFromValue = function(enumName: EnumName, value: {}): string",
+            value_type
+        ));
+    }
     interface.push_str("\n\t\tlocal dict = invValueTree[enumName]");
     interface.push_str("\n\t\tassert(dict, `invalid enumName: \"{enumName}\"`)");
     interface.push_str("\n\t\tlocal name = dict[value]");
     interface.push_str("\n\t\tassert(name, `invalid value: \"{enumName}\" -> {value}`)");
     interface.push_str("\n\t\treturn name");
-    interface.push_str(&format!("\n\tend :: {},", get_name_union_type));
-    interface
-        .push_str("\n\tgetValueFromEnumItem = function(enumName: EnumName, name: string): number");
+    interface.push_str(&get_function_end(use_overloads, &get_name_union_type));
+    interface.push_str(&format!(
+        "\n\tgetValueFromEnumItem = function(enumName: EnumName, name: string): {}",
+        value_type
+    ));
     interface.push_str("\n\t\tlocal dict = valueTree[enumName]");
     interface.push_str("\n\t\tassert(dict, `invalid enumName: \"{enumName}\"`)");
     interface.push_str("\n\t\tlocal value = dict[name]");
     interface.push_str("\n\t\tassert(value, `invalid value: \"{enumName}\" -> \"{name}\"`)");
     interface.push_str("\n\t\treturn value");
-    interface.push_str(&format!("\n\tend :: {},", get_value_union_type));
+    interface.push_str(&get_function_end(use_overloads, &get_value_union_type));
+    interface.push_str("\n\tisEnumItem = function(enumName: EnumName, name: string): boolean");
+    interface.push_str("\n\t\tlocal dict = valueTree[enumName]");
+    interface.push_str("\n\t\tassert(dict, `invalid enumName: \"{enumName}\"`)");
+    interface.push_str("\n\t\tlocal value = dict[name]");
+    interface.push_str("\n\t\treturn value ~= nil and invValueTree[enumName][value] == name");
+    interface.push_str("\n\tend,");
 
-    for e in config.enums.iter() {
-        interface.push_str(&format!(
-            "\n\t{} = {},",
+    interface.push_str("\n\tcompare = function(enumName: EnumName, a: string, b: string): number");
+    interface.push_str("\n\t\tlocal index = indexTree[enumName]");
+    interface.push_str("\n\t\tassert(index, `invalid enumName: \"{enumName}\"`)");
+    interface.push_str("\n\t\tlocal aIndex, bIndex = index[a], index[b]");
+    interface.push_str("\n\t\tassert(aIndex, `invalid value: \"{enumName}\" -> \"{a}\"`)");
+    interface.push_str("\n\t\tassert(bIndex, `invalid value: \"{enumName}\" -> \"{b}\"`)");
+    interface
+        .push_str("\n\t\treturn if aIndex < bIndex then -1 elseif aIndex > bIndex then 1 else 0");
+    interface.push_str(&get_function_end(
+        use_overloads,
+        &get_overload_type(&enums, |e| {
+            format!(
+                "(\"{}\", {}, {}) -> number",
+                e.get_full_name(),
+                e.name,
+                e.name
+            )
+        }),
+    ));
+    let get_step_union_type = get_overload_type(&enums, |e| {
+        format!(
+            "(\"{}\", {}, boolean?) -> {}?",
+            e.get_full_name(),
             e.name,
-            EnumDictDefinition {
-                value: e.clone(),
-                is_frozen: true,
-                assign_as_static_string: config.assign_static_strings
-            }
-            .get_variable_name(),
-        ))
+            e.name
+        )
+    });
+    interface
+        .push_str("\n\tnext = function(enumName: EnumName, name: string, wrap: boolean?): string?");
+    push_order_lookup(&mut interface, "name");
+    interface.push_str("\n\t\tif nameIndex < #order then");
+    interface.push_str("\n\t\t\treturn order[nameIndex + 1]");
+    interface.push_str("\n\t\tend");
+    interface.push_str("\n\t\treturn if wrap then order[1] else nil");
+    interface.push_str(&get_function_end(use_overloads, &get_step_union_type));
+    interface.push_str(
+        "\n\tprevious = function(enumName: EnumName, name: string, wrap: boolean?): string?",
+    );
+    push_order_lookup(&mut interface, "name");
+    interface.push_str("\n\t\tif nameIndex > 1 then");
+    interface.push_str("\n\t\t\treturn order[nameIndex - 1]");
+    interface.push_str("\n\t\tend");
+    interface.push_str("\n\t\treturn if wrap then order[#order] else nil");
+    interface.push_str(&get_function_end(use_overloads, &get_step_union_type));
+    let get_end_union_type = get_overload_type(&enums, |e| {
+        format!("(\"{}\") -> {}", e.get_full_name(), e.name)
+    });
+    for (helper_name, index) in [("first", "1"), ("last", "#order")] {
+        if use_overloads {
+            interface.push_str(&format!(
+                "\n\t{} = function(enumName: EnumName)",
+                helper_name
+            ));
+        } else {
+            interface.push_str(&format!(
+                "\n\t{} = function(enumName: EnumName): string",
+                helper_name
+            ));
+        }
+        interface.push_str("\n\t\tlocal order = orderTree[enumName]");
+        interface.push_str("\n\t\tassert(order, `invalid enumName: \"{enumName}\"`)");
+        interface.push_str(&format!("\n\t\treturn order[{}]", index));
+        interface.push_str(&get_function_end(use_overloads, &get_end_union_type));
+    }
+    interface.push_str("\n\tcount = function(enumName: EnumName): number");
+    interface.push_str("\n\t\tlocal order = orderTree[enumName]");
+    interface.push_str("\n\t\tassert(order, `invalid enumName: \"{enumName}\"`)");
+    interface.push_str("\n\t\treturn #order");
+    interface.push_str("\n\tend,");
+    interface.push_str("\n\tindexOf = function(enumName: EnumName, name: string): number");
+    interface.push_str("\n\t\tlocal index = indexTree[enumName]");
+    interface.push_str("\n\t\tassert(index, `invalid enumName: \"{enumName}\"`)");
+    interface.push_str("\n\t\tlocal nameIndex = index[name]");
+    interface.push_str("\n\t\tassert(nameIndex, `invalid value: \"{enumName}\" -> \"{name}\"`)");
+    interface.push_str("\n\t\treturn nameIndex");
+    interface.push_str(&get_function_end(
+        use_overloads,
+        &get_overload_type(&enums, |e| {
+            format!("(\"{}\", {}) -> number", e.get_full_name(), e.name)
+        }),
+    ));
+
+    if !default_enums.is_empty() {
+        let default_value_type = get_luau_value_type(&default_enums);
+        if use_overloads {
+            interface.push_str("\n\tgetDefault = function(enumName: EnumName)");
+        } else {
+            interface.push_str("\n\tgetDefault = function(enumName: EnumName): string");
+        }
+        push_default_lookup(&mut interface);
+        interface.push_str("\n\t\treturn default");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&default_enums, |e| {
+                format!("(\"{}\") -> {}", e.get_full_name(), e.name)
+            }),
+        ));
+        interface.push_str(&format!(
+            "\n\tgetEnumItemFromValueOrDefault = function(enumName: EnumName, value: ({})?): string",
+            default_value_type
+        ));
+        push_default_lookup(&mut interface);
+        interface.push_str(
+            "\n\t\tlocal name = if value ~= nil then invValueTree[enumName][value] else nil",
+        );
+        interface.push_str("\n\t\treturn if name ~= nil then name else default");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&default_enums, |e| {
+                format!(
+                    "(\"{}\", {}?) -> {}",
+                    e.get_full_name(),
+                    e.kind.get_luau_type(),
+                    e.name
+                )
+            }),
+        ));
+        interface.push_str(&format!(
+            "\n\tgetValueFromEnumItemOrDefault = function(enumName: EnumName, name: string?): {}",
+            default_value_type
+        ));
+        push_default_lookup(&mut interface);
+        interface.push_str("\n\t\tlocal dict = valueTree[enumName]");
+        interface.push_str("\n\t\tlocal value = if name ~= nil then dict[name] else nil");
+        interface.push_str("\n\t\treturn if value ~= nil then value else dict[default]");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&default_enums, |e| {
+                format!(
+                    "(\"{}\", string?) -> {}",
+                    e.get_full_name(),
+                    e.kind.get_luau_type()
+                )
+            }),
+        ));
+    }
+
+    if has_metadata {
+        let get_text_union_type = |return_type: &str| {
+            get_overload_type(&enums, |e| {
+                format!("(\"{}\", {}) -> {}", e.get_full_name(), e.name, return_type)
+            })
+        };
+        interface
+            .push_str("\n\tgetDisplayName = function(enumName: EnumName, name: string): string");
+        push_metadata_lookup(&mut interface);
+        interface.push_str(
+            "\n\t\treturn if metadata and metadata.display then metadata.display else item",
+        );
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_text_union_type("string"),
+        ));
+        interface
+            .push_str("\n\tgetDescription = function(enumName: EnumName, name: string): string?");
+        push_metadata_lookup(&mut interface);
+        interface.push_str("\n\t\treturn if metadata then metadata.description else nil");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_text_union_type("string?"),
+        ));
+        interface.push_str(
+            "\n\thasTag = function(enumName: EnumName, name: string, tag: string): boolean",
+        );
+        push_metadata_lookup(&mut interface);
+        interface.push_str(
+            "\n\t\treturn if metadata and metadata.tags then metadata.tags[tag] == true else false",
+        );
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&enums, |e| {
+                format!("(\"{}\", {}, string) -> boolean", e.get_full_name(), e.name)
+            }),
+        ));
+    }
+
+    if has_retired_values {
+        interface
+            .push_str("\n\tisRetiredValue = function(enumName: EnumName, value: number): boolean");
+        interface.push_str("\n\t\tassert(valueTree[enumName], `invalid enumName: \"{enumName}\"`)");
+        interface.push_str(&format!(
+            "\n\t\tfor _, range in {}[enumName] or {{}} do",
+            RETIRED_TREE_NAME
+        ));
+        interface.push_str("\n\t\t\tif value >= range[1] and value <= range[2] then");
+        interface.push_str("\n\t\t\t\treturn true");
+        interface.push_str("\n\t\t\tend");
+        interface.push_str("\n\t\tend");
+        interface.push_str("\n\t\treturn false");
+        let numeric_enums: Vec<Enum> = enums
+            .iter()
+            .filter(|e| e.kind != EnumKind::String)
+            .cloned()
+            .collect();
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&numeric_enums, |e| {
+                format!("(\"{}\", number) -> boolean", e.get_full_name())
+            }),
+        ));
     }
 
+    if !migration_enums.is_empty() {
+        interface
+            .push_str("\n\tmigrate = function(enumName: EnumName, old: string | number): string?");
+        interface.push_str("\n\t\tlocal dict = valueTree[enumName]");
+        interface.push_str("\n\t\tassert(dict, `invalid enumName: \"{enumName}\"`)");
+        // migrations come first, so that renumbered values resolve to the item they used to be
+        interface.push_str(&format!(
+            "\n\t\tlocal migrations = {}[enumName]",
+            MIGRATION_TREE_NAME
+        ));
+        interface.push_str("\n\t\tif migrations and migrations[old] then");
+        interface.push_str("\n\t\t\treturn migrations[old]");
+        interface.push_str("\n\t\tend");
+        interface.push_str("\n\t\tif type(old) == \"string\" and dict[old] ~= nil then");
+        interface.push_str("\n\t\t\treturn invValueTree[enumName][dict[old]]");
+        interface.push_str("\n\t\tend");
+        interface.push_str("\n\t\treturn invValueTree[enumName][old]");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&enums, |e| {
+                format!(
+                    "(\"{}\", string | number) -> {}?",
+                    e.get_full_name(),
+                    e.name
+                )
+            }),
+        ));
+    }
+
+    if !text_enums.is_empty() {
+        interface.push_str(
+            "\n\tgetLocalizedName = function(enumName: EnumName, name: string, localeId: string): string",
+        );
+        interface.push_str("\n\t\tlocal dict = valueTree[enumName]");
+        interface.push_str("\n\t\tassert(dict, `invalid enumName: \"{enumName}\"`)");
+        interface
+            .push_str("\n\t\tassert(dict[name], `invalid value: \"{enumName}\" -> \"{name}\"`)");
+        // aliases share the text of their canonical item
+        interface.push_str("\n\t\tlocal item = invValueTree[enumName][dict[name]]");
+        // a regional locale such as `en-us` falls back to its language
+        interface.push_str(&format!(
+            "\n\t\tlocal locale = {}[localeId] or {}[string.split(localeId, \"-\")[1]]",
+            LOCALIZATION_TREE_NAME, LOCALIZATION_TREE_NAME
+        ));
+        interface.push_str(
+            "\n\t\tlocal text = if locale and locale[enumName] then locale[enumName][item] else nil",
+        );
+        interface.push_str("\n\t\tif text then");
+        interface.push_str("\n\t\t\treturn text");
+        interface.push_str("\n\t\tend");
+        if has_metadata {
+            interface.push_str(&format!(
+                "\n\t\tlocal metadata = if {}[enumName] then {}[enumName][item] else nil",
+                METADATA_TREE_NAME, METADATA_TREE_NAME
+            ));
+            interface.push_str(
+                "\n\t\treturn if metadata and metadata.display then metadata.display else item",
+            );
+        } else {
+            interface.push_str("\n\t\treturn item");
+        }
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&enums, |e| {
+                format!("(\"{}\", {}, string) -> string", e.get_full_name(), e.name)
+            }),
+        ));
+    }
+
+    if !data_enums.is_empty() {
+        interface.push_str(
+            "\n\tgetData = function(enumName: EnumName, name: string): { [string]: any }",
+        );
+        interface.push_str(&format!("\n\t\tlocal dict = {}[enumName]", DATA_TREE_NAME));
+        interface.push_str("\n\t\tassert(dict, `enum has no data: \"{enumName}\"`)");
+        interface.push_str("\n\t\tlocal data = dict[name]");
+        interface.push_str("\n\t\tassert(data, `invalid value: \"{enumName}\" -> \"{name}\"`)");
+        interface.push_str("\n\t\treturn data");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&data_enums, |e| {
+                format!(
+                    "(\"{}\", {}) -> {}",
+                    e.get_full_name(),
+                    e.name,
+                    e.get_data_type_name()
+                )
+            }),
+        ));
+    }
+
+    if !flag_enums.is_empty() {
+        let get_flag_union_type = |return_type: &str| {
+            get_overload_type(&flag_enums, |e| {
+                format!(
+                    "(\"{}\", number, {}) -> {}",
+                    e.get_full_name(),
+                    e.name,
+                    return_type
+                )
+            })
+        };
+        interface.push_str(
+            "\n\thasFlag = function(enumName: EnumName, flags: number, name: string): boolean",
+        );
+        push_flag_lookup(&mut interface);
+        interface.push_str("\n\t\treturn bit32.btest(flags, value)");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_flag_union_type("boolean"),
+        ));
+        interface.push_str(
+            "\n\taddFlag = function(enumName: EnumName, flags: number, name: string): number",
+        );
+        push_flag_lookup(&mut interface);
+        interface.push_str("\n\t\treturn bit32.bor(flags, value)");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_flag_union_type("number"),
+        ));
+        interface.push_str(
+            "\n\tremoveFlag = function(enumName: EnumName, flags: number, name: string): number",
+        );
+        push_flag_lookup(&mut interface);
+        interface.push_str("\n\t\treturn bit32.band(flags, bit32.bnot(value))");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_flag_union_type("number"),
+        ));
+        interface
+            .push_str("\n\tflagsToList = function(enumName: EnumName, flags: number): { string }");
+        interface.push_str(&format!("\n\t\tlocal dict = {}[enumName]", FLAG_TREE_NAME));
+        interface.push_str("\n\t\tassert(dict, `enum is not a flag enum: \"{enumName}\"`)");
+        interface.push_str("\n\t\tlocal list = {}");
+        // deprecated items are left out of listTree, but their bits still belong in the list
+        interface.push_str("\n\t\tfor _, name in orderTree[enumName] do");
+        interface.push_str("\n\t\t\tif bit32.btest(flags, dict[name]) then");
+        interface.push_str("\n\t\t\t\ttable.insert(list, name)");
+        interface.push_str("\n\t\t\tend");
+        interface.push_str("\n\t\tend");
+        interface.push_str("\n\t\treturn list");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&flag_enums, |e| {
+                format!("(\"{}\", number) -> {{ {} }}", e.get_full_name(), e.name)
+            }),
+        ));
+        interface.push_str(
+            "\n\tflagsFromList = function(enumName: EnumName, names: { string }): number",
+        );
+        interface.push_str(&format!("\n\t\tlocal dict = {}[enumName]", FLAG_TREE_NAME));
+        interface.push_str("\n\t\tassert(dict, `enum is not a flag enum: \"{enumName}\"`)");
+        interface.push_str("\n\t\tlocal flags = 0");
+        interface.push_str("\n\t\tfor _, name in names do");
+        interface.push_str("\n\t\t\tlocal value = dict[name]");
+        interface.push_str("\n\t\t\tassert(value, `invalid value: \"{enumName}\" -> \"{name}\"`)");
+        interface.push_str("\n\t\t\tflags = bit32.bor(flags, value)");
+        interface.push_str("\n\t\tend");
+        interface.push_str("\n\t\treturn flags");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&flag_enums, |e| {
+                format!("(\"{}\", {{ {} }}) -> number", e.get_full_name(), e.name)
+            }),
+        ));
+    }
+
+    if !child_enums.is_empty() {
+        let get_parent_name = |e: &Enum| {
+            e.parent
+                .as_ref()
+                .map(|parent| parent.name.to_string())
+                .unwrap_or_default()
+        };
+        interface.push_str("\n\ttoParent = function(enumName: EnumName, name: string): string?");
+        interface.push_str(&format!(
+            "\n\t\tlocal parentName = {}[enumName]",
+            PARENT_TREE_NAME
+        ));
+        interface.push_str("\n\t\tassert(parentName, `enum has no parent: \"{enumName}\"`)");
+        interface.push_str("\n\t\tlocal value = valueTree[enumName][name]");
+        interface.push_str("\n\t\tassert(value, `invalid value: \"{enumName}\" -> \"{name}\"`)");
+        interface.push_str("\n\t\treturn invValueTree[parentName][value]");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&child_enums, |e| {
+                format!(
+                    "(\"{}\", {}) -> {}?",
+                    e.get_full_name(),
+                    e.name,
+                    get_parent_name(e)
+                )
+            }),
+        ));
+        interface.push_str("\n\tfromParent = function(enumName: EnumName, name: string): string?");
+        interface.push_str(&format!(
+            "\n\t\tlocal parentName = {}[enumName]",
+            PARENT_TREE_NAME
+        ));
+        interface.push_str("\n\t\tassert(parentName, `enum has no parent: \"{enumName}\"`)");
+        interface.push_str("\n\t\tlocal value = valueTree[parentName][name]");
+        interface.push_str("\n\t\tassert(value, `invalid value: \"{parentName}\" -> \"{name}\"`)");
+        interface.push_str("\n\t\treturn invValueTree[enumName][value]");
+        interface.push_str(&get_function_end(
+            use_overloads,
+            &get_overload_type(&child_enums, |e| {
+                format!(
+                    "(\"{}\", {}) -> {}?",
+                    e.get_full_name(),
+                    get_parent_name(e),
+                    e.name
+                )
+            }),
+        ));
+    }
+
+    let entries: Vec<(Vec<LuauName>, String)> = enums
+        .iter()
+        .map(|e| {
+            (
+                e.get_path(),
+                EnumDictDefinition {
+                    value: e.clone(),
+                    is_frozen: true,
+                    assign_as_static_string: config.assign_static_strings,
+                }
+                .get_variable_name(),
+            )
+        })
+        .collect();
+    push_namespace(&mut interface, &entries, 1);
+
     interface.push_str("\n}");
 
     let mut code = String::new();
     code.push_str(&format!(
-        "{}{}{}{}{}{}",
-        header, list_tree, value_tree, inv_value_tree, enum_name_type, interface
+        "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
+        header,
+        list_tree,
+        value_tree,
+        inv_value_tree,
+        order_tree,
+        index_tree,
+        metadata_tree,
+        data_tree,
+        flag_tree,
+        parent_tree,
+        default_tree,
+        retired_tree,
+        migration_tree,
+        localization_tree,
+        enum_name_type,
+        interface
     ));
 
-    code = format_code(code);
-    return code;
+    format_code(code)
 }
 
 #[cfg(test)]
@@ -436,6 +1900,381 @@ pub mod config_test {
     fn parse_config() {
         let config: EnumSetConfig =
             toml::from_str(crate::model::config_test::TOML_STR).expect("Failed to parse toml");
-        println!("result:\n{}", dump(config));
+        println!("result:\n{}", dump(config).expect("Failed to dump"));
+    }
+
+    #[test]
+    fn dump_is_deterministic() {
+        let config: EnumSetConfig =
+            toml::from_str(crate::model::config_test::TOML_STR).expect("Failed to parse toml");
+        let first = dump(config.clone()).expect("Failed to dump");
+        assert_eq!(first, dump(config).expect("Failed to dump"));
+        let map_type = first.find("MapType =").expect("MapType missing");
+        let tool_event = first.find("ToolEvent =").expect("ToolEvent missing");
+        assert!(map_type < tool_event);
+    }
+
+    #[test]
+    fn reject_identifier_collisions() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+Foo = ["A"]
+FooValue = ["B"]
+EnumName = ["C"]
+getEnumItems = ["D"]
+"#,
+        )
+        .expect("Failed to parse toml");
+        let error = dump(config).expect_err("Collisions were not detected");
+        assert_eq!(
+            error,
+            [
+                "enum 'FooValue' generates local 'FooValueDict', which clashes with enum 'Foo'",
+                "enum 'EnumName' generates type 'EnumName', which clashes with the type 'EnumName'",
+                "enum 'getEnumItems' generates field 'getEnumItems', which clashes with the helper function 'getEnumItems'",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn dump_without_union_types() {
+        let config: EnumSetConfig = toml::from_str(
+            &crate::model::config_test::TOML_STR
+                .replace(
+                    "use_union_types_for_export = true",
+                    "use_union_types_for_export = false",
+                )
+                .replace(
+                    "use_union_types_for_parameters = true",
+                    "use_union_types_for_parameters = false",
+                ),
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("export type MapType = string"));
+        assert!(code.contains(
+            "getEnumItemFromValue = function(enumName: EnumName, value: number): string"
+        ));
+        assert!(!code.contains("end :: "));
+    }
+
+    #[test]
+    fn dump_item_metadata() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+RunMode = ["Dev", "Live"]
+[enums.MapType]
+PowerLab = 2
+[enums.MapType.City]
+value = 1
+display = "Big \"City\""
+description = "The main hub."
+tags = ["Urban"]
+aliases = ["Town"]
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("--- `City` (Big \"City\"): The main hub."));
+        assert!(code.contains("\t--- The main hub.\r\n\tCity = \"City\" :: MapType,"));
+        assert!(code.contains(
+            "City = table.freeze({ display = 'Big \"City\"', description = \"The main hub.\", tags = table.freeze({ [\"Urban\"] = true }) }),"
+        ));
+        assert!(code.contains("local metadataTree: { [string]: { [string]: ItemMetadata } } = {\r\n\tMapType = MapTypeMetadata,\r\n}"));
+        // `Town` is looked up as `City`
+        assert!(code.contains("\t\tlocal item = invValueTree[enumName][dict[name]]\r\n\t\tlocal metadata = if metadataTree[enumName] then metadataTree[enumName][item] else nil\r\n\t\treturn if metadata and metadata.display then metadata.display else item"));
+        assert!(code.contains("end :: ((\"RunMode\", RunMode, string) -> boolean) & ((\"MapType\", MapType, string) -> boolean),"));
+    }
+
+    #[test]
+    fn dump_item_data() {
+        let config: EnumSetConfig =
+            toml::from_str(crate::model::config_test::PAYLOAD_STR).expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("export type ToolClassIdData = { Damage: number, Icon: string, TwoHanded: boolean, Tint: Color3, Offset: Vector3, Context: EquipContext }"));
+        assert!(code.contains("BladeCutlass = table.freeze({ Damage = 12.5, Icon = \"rbxassetid://1\", TwoHanded = false, Tint = Color3.fromRGB(255, 0, 0), Offset = Vector3.new(0, 1, 0), Context = \"Right\" :: EquipContext }),"));
+        assert!(code.contains("end :: (\"ToolClassId\", ToolClassId) -> ToolClassIdData,"));
+    }
+
+    #[test]
+    fn dump_flags() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+exclude_deprecated_from_lists = true
+[enums]
+RunMode = ["Dev", "Live"]
+[enums.Permission]
+kind = "flags"
+items = ["Read", "Write", { name = "Admin", deprecated = true }]
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("\tRead = 1,\r\n\tWrite = 2,\r\n\tAdmin = 4,"));
+        assert!(code.contains("local flagTree: { [string]: { [string]: number } } = {\r\n\tPermission = PermissionValueDict,\r\n}"));
+        assert!(code.contains("return bit32.band(flags, bit32.bnot(value))"));
+        assert!(code.contains("end :: (\"Permission\", { Permission }) -> number,"));
+        // deprecated flags are still listed when they are set
+        assert!(code.contains("\t\tfor _, name in orderTree[enumName] do"));
+    }
+
+    #[test]
+    fn dump_aliases() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums.MapType]
+City = 1
+Laboratory = { value = 2, aliases = ["PowerLab"] }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("export type MapType = \"City\" | \"Laboratory\"\r\n"));
+        assert!(code
+            .contains("\t--- Alias of `Laboratory`.\r\n\tPowerLab = \"Laboratory\" :: MapType,"));
+        assert!(code.contains("\tLaboratory = 2,\r\n\tPowerLab = 2,"));
+        assert!(code.contains("local MapTypeList = table.freeze({ \"City\", \"Laboratory\" })"));
+    }
+
+    #[test]
+    fn dump_string_values() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+RunMode = ["Dev", "Live"]
+[enums.AnalyticsEvent]
+Purchase = "purchase_v2"
+Refund = "refund"
+Restore = "restore\u0000\u001b"
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("\tPurchase = \"purchase_v2\",\r\n\tRefund = \"refund\","));
+        assert!(
+            code.contains("\t[\"purchase_v2\"] = \"Purchase\",\r\n\t[\"refund\"] = \"Refund\",")
+        );
+        assert!(code.contains(
+            "getEnumItemFromValue = function(enumName: EnumName, value: number | string)"
+        ));
+        assert!(code.contains(
+            "((\"RunMode\", number) -> RunMode) & ((\"AnalyticsEvent\", string) -> AnalyticsEvent)"
+        ));
+        assert!(code.contains("((\"AnalyticsEvent\", AnalyticsEvent) -> string)"));
+        assert!(code.contains("\tRestore = \"restore\\u{0000}\\u{001b}\","));
+    }
+
+    #[test]
+    fn dump_defaults() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+RunMode = ["Dev", "Live"]
+MapType = { default = "City", items = ["City", "PowerLab"] }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code
+            .contains("local defaultTree: { [string]: string } = {\r\n\tMapType = \"City\",\r\n}"));
+        assert!(code.contains("end :: (\"MapType\") -> MapType,"));
+        assert!(code.contains("end :: (\"MapType\", number?) -> MapType,"));
+        assert!(code.contains("end :: (\"MapType\", string?) -> number,"));
+        assert!(code.contains("return if name ~= nil then name else default"));
+    }
+
+    #[test]
+    fn dump_ordering_helpers() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+Rank = ["Bronze", { name = "Gold", value = 10 }, { name = "Silver", value = 5, aliases = ["Iron"] }]
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(
+            code.contains("local RankOrder = table.freeze({ \"Bronze\", \"Silver\", \"Gold\" })")
+        );
+        assert!(code.contains("\tSilver = 2,\r\n\tIron = 2,\r\n\tGold = 3,"));
+        assert!(code.contains("end :: (\"Rank\", Rank, Rank) -> number,"));
+        assert!(code.contains("end :: (\"Rank\", Rank, boolean?) -> Rank?,"));
+        assert!(code.contains("end :: (\"Rank\") -> Rank,"));
+        assert!(code.contains("return if wrap then order[#order] else nil"));
+    }
+
+    #[test]
+    fn dump_localization() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+RunMode = ["Dev", "Live"]
+
+[enums.MapType.City]
+value = 1
+text = { en = "City", "pt-br" = "Cidade" }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains(
+            "\ten = table.freeze({\r\n\t\tMapType = table.freeze({ City = \"City\" }),\r\n\t}),"
+        ));
+        assert!(code.contains("\t[\"pt-br\"] = table.freeze({"));
+        assert!(code.contains("end :: ((\"RunMode\", RunMode, string) -> string) & ((\"MapType\", MapType, string) -> string),"));
+        assert!(code.contains("\t\treturn item\r\n"));
+    }
+
+    #[test]
+    fn dump_retired_values() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+RunMode = ["Dev", "Live"]
+MapType = { reserved = [2, "7..9"], items = ["City", "PowerLab"] }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("\tMapType = table.freeze({ { 2, 2 }, { 7, 9 } }),"));
+        assert!(code.contains("\tCity = 1,\r\n\tPowerLab = 3,"));
+        assert!(code.contains(
+            "end :: ((\"RunMode\", number) -> boolean) & ((\"MapType\", number) -> boolean),"
+        ));
+    }
+
+    #[test]
+    fn dump_migrations() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+MapType = ["City", "PowerLab"]
+AnalyticsEvent = { Purchase = "purchase_v2" }
+
+[migrations.MapType]
+Town = "City"
+3 = "PowerLab"
+
+[migrations.AnalyticsEvent]
+"purchase-v1" = "Purchase"
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("local MapTypeMigrations: { [any]: MapType } = table.freeze({\r\n\tTown = \"City\",\r\n\t[3] = \"PowerLab\",\r\n})"));
+        assert!(code.contains("\t[\"purchase-v1\"] = \"Purchase\","));
+        assert!(code.contains("(\"MapType\", string | number) -> MapType?"));
+        assert!(code.contains("return migrations[old]"));
+    }
+
+    #[test]
+    fn dump_parent_helpers() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+EquipContext = ["Right", "Left"]
+DualEquipContext = { extends = "EquipContext", items = ["Dual"] }
+MainHand = { subset_of = "EquipContext", items = ["Right"] }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code
+            .contains("\tDualEquipContext = \"EquipContext\",\r\n\tMainHand = \"EquipContext\","));
+        assert!(code.contains("\tRight = 1,\r\n\tLeft = 2,\r\n\tDual = 3,"));
+        assert!(code.contains("(\"MainHand\", MainHand) -> EquipContext?"));
+        assert!(code.contains("(\"MainHand\", EquipContext) -> MainHand?"));
+    }
+
+    #[test]
+    fn dump_namespaces() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+MapType = ["City"]
+Tool.ClassId = ["BladeCutlass"]
+Tool.Event = ["Equipped"]
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("export type ToolClassId = \"BladeCutlass\"\r\n"));
+        assert!(code.contains("\t[\"Tool.ClassId\"] = ToolClassIdList,"));
+        assert!(code.contains(
+            "export type EnumName = \"MapType\" | \"Tool.ClassId\" | \"Tool.Event\"\r\n"
+        ));
+        assert!(code.contains("((\"Tool.Event\", number) -> ToolEvent)"));
+        assert!(code.contains("\tTool = table.freeze({\r\n\t\tClassId = ToolClassIdDict,\r\n\t\tEvent = ToolEventDict,\r\n\t}),"));
+
+        let config: EnumSetConfig = toml::from_str(
+            &config_str
+                .replace(
+                    "Tool.ClassId = [",
+                    "Tool = [\"Hammer\"]\n\"Tool.ClassId\" = [",
+                )
+                .replace("Tool.Event", "\"Tool.Event\""),
+        )
+        .expect("Failed to parse toml");
+        let error = dump(config).expect_err("Namespace collision passed");
+        assert!(error.contains("enum 'Tool' is also used as a namespace by enum 'Tool.ClassId'"));
+    }
+
+    #[test]
+    fn dump_deprecated_items() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+exclude_deprecated_from_lists = true
+[enums.MapType]
+City = 1
+PowerLab = { value = 2, deprecated = "use City instead" }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let code = dump(config).expect("Failed to dump");
+        assert!(code.contains("--- `PowerLab` (deprecated: use City instead)\r\n"));
+        assert!(code.contains(
+            "\t--- @deprecated use City instead\r\n\tPowerLab = \"PowerLab\" :: MapType,"
+        ));
+        assert!(code.contains("local MapTypeList = table.freeze({ \"City\" })"));
     }
 }
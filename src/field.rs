@@ -0,0 +1,188 @@
+use crate::model::{Enum, LuauName};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct FieldNumber(pub f64);
+
+// NaN and infinities are rejected when parsing, so equality is total
+impl Eq for FieldNumber {}
+
+impl TryFrom<f64> for FieldNumber {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_finite() {
+            Ok(FieldNumber(value))
+        } else {
+            Err(format!("{} is not a finite number", value))
+        }
+    }
+}
+
+impl From<FieldNumber> for f64 {
+    fn from(value: FieldNumber) -> Self {
+        value.0
+    }
+}
+
+impl Hash for FieldNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl fmt::Display for FieldNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub enum FieldType {
+    Number,
+    String,
+    Boolean,
+    Color3,
+    Vector3,
+    Enum(LuauName),
+}
+
+impl FieldType {
+    // Field types that are not enums, whose names enums can not take
+    pub const BUILT_IN: &'static [FieldType] = &[
+        FieldType::Number,
+        FieldType::String,
+        FieldType::Boolean,
+        FieldType::Color3,
+        FieldType::Vector3,
+    ];
+}
+
+impl FromStr for FieldType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "number" => Ok(FieldType::Number),
+            "string" => Ok(FieldType::String),
+            "boolean" => Ok(FieldType::Boolean),
+            "Color3" => Ok(FieldType::Color3),
+            "Vector3" => Ok(FieldType::Vector3),
+            _ => LuauName::from_path(s).map(FieldType::Enum).map_err(|_| {
+                format!(
+                    "Field type '{}' must be number, string, boolean, Color3, Vector3 or an enum name",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for FieldType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        FieldType::from_str(&value)
+    }
+}
+
+impl From<FieldType> for String {
+    fn from(value: FieldType) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldType::Number => write!(f, "number"),
+            FieldType::String => write!(f, "string"),
+            FieldType::Boolean => write!(f, "boolean"),
+            FieldType::Color3 => write!(f, "Color3"),
+            FieldType::Vector3 => write!(f, "Vector3"),
+            FieldType::Enum(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct EnumField {
+    pub name: LuauName,
+    pub field_type: FieldType,
+}
+
+// Raw payload values, their meaning depends on the type of the field they are assigned to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum FieldValue {
+    Boolean(bool),
+    Number(FieldNumber),
+    Text(String),
+    Vector([FieldNumber; 3]),
+}
+
+fn is_hex_color(text: &str) -> bool {
+    let digits = text.strip_prefix('#').unwrap_or(text);
+    digits.len() == 6 && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+impl FieldValue {
+    pub fn check(&self, field_type: &FieldType, enums: &[Enum]) -> Result<(), String> {
+        match (field_type, self) {
+            (FieldType::Number, FieldValue::Number(_))
+            | (FieldType::String, FieldValue::Text(_))
+            | (FieldType::Boolean, FieldValue::Boolean(_))
+            | (FieldType::Vector3, FieldValue::Vector(_)) => Ok(()),
+            (FieldType::Color3, FieldValue::Vector(rgb)) => {
+                if rgb.iter().all(|c| (0.0..=255.0).contains(&c.0)) {
+                    Ok(())
+                } else {
+                    Err("Color3 components must be between 0 and 255".to_string())
+                }
+            }
+            (FieldType::Color3, FieldValue::Text(hex)) => {
+                if is_hex_color(hex) {
+                    Ok(())
+                } else {
+                    Err(format!("'{}' is not a hex color like \"#ff0000\"", hex))
+                }
+            }
+            (FieldType::Enum(enum_name), FieldValue::Text(item_name)) => {
+                let e = enums
+                    .iter()
+                    .find(|e| e.name == *enum_name)
+                    .ok_or_else(|| format!("unknown enum '{}'", enum_name))?;
+                if e.items.iter().any(|item| item.name.as_str() == item_name) {
+                    Ok(())
+                } else {
+                    Err(format!("'{}' is not an item of '{}'", item_name, enum_name))
+                }
+            }
+            (_, _) => Err(format!("expected {}", field_type)),
+        }
+    }
+
+    pub fn to_luau(&self, field_type: &FieldType) -> String {
+        match (field_type, self) {
+            (FieldType::Color3, FieldValue::Vector([r, g, b])) => {
+                format!("Color3.fromRGB({}, {}, {})", r, g, b)
+            }
+            (FieldType::Color3, FieldValue::Text(hex)) => format!("Color3.fromHex(\"{}\")", hex),
+            (FieldType::Vector3, FieldValue::Vector([x, y, z])) => {
+                format!("Vector3.new({}, {}, {})", x, y, z)
+            }
+            (FieldType::Enum(enum_name), FieldValue::Text(item_name)) => {
+                format!("\"{}\" :: {}", item_name, enum_name)
+            }
+            (_, FieldValue::Boolean(value)) => value.to_string(),
+            (_, FieldValue::Number(value)) => value.to_string(),
+            (_, FieldValue::Text(text)) => crate::document::to_luau_string(text),
+            (_, FieldValue::Vector([x, y, z])) => format!("{{ {}, {}, {} }}", x, y, z),
+        }
+    }
+}
@@ -0,0 +1,579 @@
+use crate::model::EnumSetConfig;
+use full_moon::ast::{Expression, Field, LastStmt, UnOp};
+use full_moon::tokenizer::{Symbol, TokenType};
+use indexmap::IndexMap;
+use serde::de::value::{Error as ValueError, MapDeserializer, SeqDeserializer};
+use serde::de::{self, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+pub type ConfigTable = IndexMap<String, ConfigValue>;
+
+// A value read from any supported config format, tables keep the order they were written in
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<ConfigValue>),
+    Table(ConfigTable),
+}
+
+impl ConfigValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ConfigValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            ConfigValue::Integer(integer) => Some(*integer),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<ConfigValue>> {
+        match self {
+            ConfigValue::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    pub fn as_table(&self) -> Option<&ConfigTable> {
+        match self {
+            ConfigValue::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ConfigValue::Boolean(_) => "boolean",
+            ConfigValue::Integer(_) => "integer",
+            ConfigValue::Float(_) => "float",
+            ConfigValue::String(_) => "string",
+            ConfigValue::Array(_) => "array",
+            ConfigValue::Table(_) => "table",
+        }
+    }
+
+    pub fn is_array(&self) -> bool {
+        self.as_array().is_some()
+    }
+
+    pub fn is_table(&self) -> bool {
+        self.as_table().is_some()
+    }
+}
+
+impl fmt::Display for ConfigValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigValue::Boolean(value) => write!(f, "{}", value),
+            ConfigValue::Integer(value) => write!(f, "{}", value),
+            ConfigValue::Float(value) => write!(f, "{}", value),
+            ConfigValue::String(value) => write!(f, "{:?}", value),
+            ConfigValue::Array(values) => {
+                let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+                write!(f, "[{}]", values.join(", "))
+            }
+            ConfigValue::Table(table) => {
+                let entries: Vec<String> = table
+                    .iter()
+                    .map(|(key, value)| format!("{} = {}", key, value))
+                    .collect();
+                write!(f, "{{ {} }}", entries.join(", "))
+            }
+        }
+    }
+}
+
+struct ConfigValueVisitor;
+
+impl<'de> Visitor<'de> for ConfigValueVisitor {
+    type Value = ConfigValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a boolean, number, string, array or table")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Boolean(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Integer(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<ConfigValue, E> {
+        i64::try_from(value)
+            .map(ConfigValue::Integer)
+            .map_err(|_| E::custom(format!("{} is too large", value)))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::Float(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<ConfigValue, E> {
+        Ok(ConfigValue::String(value))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<ConfigValue, E> {
+        Err(E::custom("null values are not supported"))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ConfigValue, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(ConfigValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ConfigValue, A::Error> {
+        let mut table = ConfigTable::new();
+        while let Some((key, value)) = map.next_entry::<String, ConfigValue>()? {
+            table.insert(key, value);
+        }
+        Ok(ConfigValue::Table(table))
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ConfigValueVisitor)
+    }
+}
+
+// Lets the model be deserialized from a value, whichever format it was read from
+impl<'de> Deserializer<'de> for ConfigValue {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            ConfigValue::Boolean(value) => visitor.visit_bool(value),
+            ConfigValue::Integer(value) => visitor.visit_i64(value),
+            ConfigValue::Float(value) => visitor.visit_f64(value),
+            ConfigValue::String(value) => visitor.visit_string(value),
+            ConfigValue::Array(values) => {
+                visitor.visit_seq(SeqDeserializer::new(values.into_iter()))
+            }
+            ConfigValue::Table(table) => visitor.visit_map(MapDeserializer::new(table.into_iter())),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        match self {
+            ConfigValue::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            other => Err(de::Error::custom(format!(
+                "expected a string, found {}",
+                other
+            ))),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl IntoDeserializer<'_, ValueError> for ConfigValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ConfigFormat {
+    #[default]
+    Toml,
+    Json,
+    Yaml,
+    Luau,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "toml" => Some(ConfigFormat::Toml),
+            "json" => Some(ConfigFormat::Json),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "luau" | "lua" => Some(ConfigFormat::Luau),
+            _ => None,
+        }
+    }
+
+    pub fn parse_value(&self, content: &str) -> Result<ConfigValue, String> {
+        match self {
+            ConfigFormat::Toml => {
+                toml::from_str(content).map_err(|error| error.message().trim().to_string())
+            }
+            ConfigFormat::Json => serde_json::from_str(content).map_err(|error| error.to_string()),
+            ConfigFormat::Yaml => {
+                serde_norway::from_str(content).map_err(|error| error.to_string())
+            }
+            ConfigFormat::Luau => parse_luau(content),
+        }
+    }
+
+    pub fn parse_config(&self, content: &str) -> Result<EnumSetConfig, String> {
+        EnumSetConfig::deserialize(self.parse_value(content)?).map_err(|error| error.to_string())
+    }
+}
+
+impl FromStr for ConfigFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "toml" => Ok(ConfigFormat::Toml),
+            "json" => Ok(ConfigFormat::Json),
+            "yaml" | "yml" => Ok(ConfigFormat::Yaml),
+            "luau" => Ok(ConfigFormat::Luau),
+            _ => Err(format!(
+                "Config format '{}' must be toml, json, yaml, yml or luau",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigFormat::Toml => write!(f, "toml"),
+            ConfigFormat::Json => write!(f, "json"),
+            ConfigFormat::Yaml => write!(f, "yaml"),
+            ConfigFormat::Luau => write!(f, "luau"),
+        }
+    }
+}
+
+// Follows the escapes of Luau strings, where `\x`, `\ddd` and `\u{...}` may spell out UTF-8 byte by byte
+fn unescape_luau_string(literal: &str) -> Result<String, String> {
+    let mut bytes = Vec::new();
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+        let Some(escape) = chars.next() else {
+            return Err("string ends in an unfinished escape".to_string());
+        };
+        match escape {
+            'a' => bytes.push(0x07),
+            'b' => bytes.push(0x08),
+            'f' => bytes.push(0x0c),
+            'n' => bytes.push(b'\n'),
+            'r' => bytes.push(b'\r'),
+            't' => bytes.push(b'\t'),
+            'v' => bytes.push(0x0b),
+            '\\' | '"' | '\'' => bytes.push(escape as u8),
+            // an escaped line break keeps the line break
+            '\n' => bytes.push(b'\n'),
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                bytes.push(b'\n');
+            }
+            'z' => while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {},
+            'x' => {
+                let digits: String = (0..2).filter_map(|_| chars.next()).collect();
+                let byte = u8::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| digits.len() == 2)
+                    .ok_or_else(|| format!("invalid escape `\\x{}`", digits))?;
+                bytes.push(byte);
+            }
+            '0'..='9' => {
+                let mut digits = escape.to_string();
+                while digits.len() < 3 {
+                    match chars.next_if(char::is_ascii_digit) {
+                        Some(digit) => digits.push(digit),
+                        None => break,
+                    }
+                }
+                let byte = digits
+                    .parse::<u8>()
+                    .map_err(|_| format!("invalid escape `\\{}`", digits))?;
+                bytes.push(byte);
+            }
+            'u' => {
+                let mut digits = String::new();
+                let is_closed = chars.next() == Some('{')
+                    && loop {
+                        match chars.next() {
+                            Some('}') => break true,
+                            Some(digit) if digit.is_ascii_hexdigit() => digits.push(digit),
+                            _ => break false,
+                        }
+                    };
+                let c = u32::from_str_radix(&digits, 16)
+                    .ok()
+                    .filter(|_| is_closed)
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid escape `\\u{{{}}}`", digits))?;
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            other => return Err(format!("unknown escape `\\{}`", other)),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| "string escapes are not valid UTF-8".to_string())
+}
+
+fn parse_luau_number(text: &str) -> Result<ConfigValue, String> {
+    let digits = text.replace('_', "");
+    if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        return i64::from_str_radix(hex, 16)
+            .map(ConfigValue::Integer)
+            .map_err(|_| format!("unsupported number '{}'", text));
+    }
+    if let Ok(integer) = digits.parse::<i64>() {
+        return Ok(ConfigValue::Integer(integer));
+    }
+    digits
+        .parse::<f64>()
+        .map(ConfigValue::Float)
+        .map_err(|_| format!("unsupported number '{}'", text))
+}
+
+// Only literals and tables are allowed, a Luau config is data rather than a script
+fn luau_to_value(expression: &Expression) -> Result<ConfigValue, String> {
+    match expression {
+        Expression::Parentheses { expression, .. }
+        | Expression::TypeAssertion { expression, .. } => luau_to_value(expression),
+        Expression::UnaryOperator {
+            unop: UnOp::Minus(_),
+            expression,
+        } => match luau_to_value(expression)? {
+            ConfigValue::Integer(value) => Ok(ConfigValue::Integer(-value)),
+            ConfigValue::Float(value) => Ok(ConfigValue::Float(-value)),
+            _ => Err(format!("cannot negate `{}`", expression.to_string().trim())),
+        },
+        Expression::Number(token) => parse_luau_number(&token.token().to_string()),
+        Expression::String(token) => match token.token_type() {
+            TokenType::StringLiteral {
+                literal,
+                multi_line_depth,
+                ..
+            } => Ok(ConfigValue::String(if *multi_line_depth > 0 {
+                literal.to_string()
+            } else {
+                unescape_luau_string(literal)?
+            })),
+            _ => Err(format!("unsupported string `{}`", token.to_string().trim())),
+        },
+        Expression::Symbol(token) => match token.token_type() {
+            TokenType::Symbol {
+                symbol: Symbol::True,
+            } => Ok(ConfigValue::Boolean(true)),
+            TokenType::Symbol {
+                symbol: Symbol::False,
+            } => Ok(ConfigValue::Boolean(false)),
+            _ => Err(format!("unsupported value `{}`", token.to_string().trim())),
+        },
+        Expression::TableConstructor(table) => {
+            let mut values = Vec::new();
+            let mut entries = ConfigTable::new();
+            for field in table.fields().iter() {
+                match field {
+                    Field::NoKey(value) => values.push(luau_to_value(value)?),
+                    Field::NameKey { key, value, .. } => {
+                        entries.insert(key.token().to_string(), luau_to_value(value)?);
+                    }
+                    Field::ExpressionKey { key, value, .. } => {
+                        let ConfigValue::String(key) = luau_to_value(key)? else {
+                            return Err(format!(
+                                "table keys must be strings, found `{}`",
+                                key.to_string().trim()
+                            ));
+                        };
+                        entries.insert(key, luau_to_value(value)?);
+                    }
+                    _ => return Err(format!("unsupported field `{}`", field.to_string().trim())),
+                }
+            }
+            match (values.is_empty(), entries.is_empty()) {
+                (false, false) => Err(format!(
+                    "table `{}` mixes array items with keys",
+                    table.to_string().trim()
+                )),
+                (false, true) => Ok(ConfigValue::Array(values)),
+                _ => Ok(ConfigValue::Table(entries)),
+            }
+        }
+        _ => Err(format!(
+            "unsupported expression `{}`",
+            expression.to_string().trim()
+        )),
+    }
+}
+
+fn parse_luau(content: &str) -> Result<ConfigValue, String> {
+    let ast = full_moon::parse(content).map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| error.error_message().to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    })?;
+    let Some(LastStmt::Return(returned)) = ast.nodes().last_stmt() else {
+        return Err("Luau configs must return a table".to_string());
+    };
+    let value = returned
+        .returns()
+        .iter()
+        .next()
+        .ok_or("Luau configs must return a table")?;
+    luau_to_value(value)
+}
+
+#[cfg(test)]
+pub mod format_test {
+    use super::*;
+
+    #[test]
+    fn parse_every_format() {
+        let toml_config = ConfigFormat::Toml
+            .parse_config(
+                r#"build_path = "src/Shared/Enums.luau"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+RunMode = ["Dev", "Live"]
+MapType = { City = 1, PowerLab = 2 }
+"#,
+            )
+            .expect("Failed to parse toml");
+        let json_config = ConfigFormat::Json
+            .parse_config(
+                r#"{
+    "build_path": "src/Shared/Enums.luau",
+    "use_union_types_for_export": true,
+    "use_union_types_for_parameters": true,
+    "assign_static_strings": true,
+    "enums": {
+        "RunMode": ["Dev", "Live"],
+        "MapType": { "City": 1, "PowerLab": 2 }
+    }
+}"#,
+            )
+            .expect("Failed to parse json");
+        let yaml_config = ConfigFormat::Yaml
+            .parse_config(
+                r#"build_path: src/Shared/Enums.luau
+use_union_types_for_export: true
+use_union_types_for_parameters: true
+assign_static_strings: true
+enums:
+  RunMode: [Dev, Live]
+  MapType:
+    City: 1
+    PowerLab: 2
+"#,
+            )
+            .expect("Failed to parse yaml");
+        let luau_config = ConfigFormat::Luau
+            .parse_config(
+                r#"return {
+    build_path = "src/Shared/Enums.luau",
+    use_union_types_for_export = true,
+    use_union_types_for_parameters = true,
+    assign_static_strings = true,
+    enums = {
+        RunMode = { "Dev", "Live" },
+        ["MapType"] = { City = 1, PowerLab = 0x2 },
+    },
+}"#,
+            )
+            .expect("Failed to parse luau");
+        assert_eq!(toml_config, json_config);
+        assert_eq!(toml_config, yaml_config);
+        assert_eq!(toml_config, luau_config);
+    }
+
+    #[test]
+    fn parse_format_names() {
+        assert_eq!(ConfigFormat::from_str("yml"), Ok(ConfigFormat::Yaml));
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("enums.yml")),
+            Some(ConfigFormat::Yaml)
+        );
+        assert_eq!(
+            ConfigFormat::from_str("xml"),
+            Err("Config format 'xml' must be toml, json, yaml, yml or luau".to_string())
+        );
+    }
+
+    #[test]
+    fn reject_luau_scripts() {
+        let error = ConfigFormat::Luau
+            .parse_value("return { enums = { MapType = getItems() } }")
+            .expect_err("Function call passed");
+        assert_eq!(error, "unsupported expression `getItems()`");
+    }
+
+    #[test]
+    fn unescape_luau_strings() {
+        assert_eq!(
+            unescape_luau_string(
+                r#"a\tb\\\"\'\x41\66\u{e9}\u{1F600}\z
+                c"#
+            ),
+            Ok("a\tb\\\"'AB\u{e9}\u{1F600}c".to_string())
+        );
+        assert_eq!(
+            unescape_luau_string("line\\\nbreak\\a\\b\\f\\v\\0"),
+            Ok("line\nbreak\x07\x08\x0c\x0b\0".to_string())
+        );
+        assert_eq!(unescape_luau_string(r"\xc3\xa9"), Ok("\u{e9}".to_string()));
+        assert_eq!(
+            unescape_luau_string(r"\q"),
+            Err("unknown escape `\\q`".to_string())
+        );
+        assert_eq!(
+            unescape_luau_string(r"\256"),
+            Err("invalid escape `\\256`".to_string())
+        );
+        assert_eq!(
+            unescape_luau_string(r"\u{110000}"),
+            Err("invalid escape `\\u{110000}`".to_string())
+        );
+    }
+}
@@ -0,0 +1,435 @@
+use crate::diagnostic::{
+    check_toml_syntax, find_toml_span, ConfigError, ConfigKey, Diagnostic, Severity,
+};
+use crate::format::{ConfigFormat, ConfigTable, ConfigValue};
+use crate::lock::ValueLock;
+use crate::model::{is_namespace, EnumSetConfig};
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+struct SourceFile {
+    path: PathBuf,
+    content: String,
+    format: ConfigFormat,
+}
+
+impl SourceFile {
+    fn error(&self, message: String, span: Option<Range<usize>>) -> Diagnostic {
+        Diagnostic::error(message, &self.path, &self.content, span)
+    }
+
+    // Spans are only known for TOML files
+    fn find_span(&self, keys: &[ConfigKey]) -> Option<Range<usize>> {
+        match self.format {
+            ConfigFormat::Toml => find_toml_span(&self.content, keys, true),
+            _ => None,
+        }
+    }
+
+    fn find_root_span(&self, key: &str) -> Option<Range<usize>> {
+        self.find_span(&[ConfigKey::from(key)])
+    }
+
+    // Follows the raw keys of the namespaces an enum is nested in
+    fn find_enum_span(&self, keys: &[String]) -> Option<Range<usize>> {
+        let keys: Vec<ConfigKey> = std::iter::once("enums")
+            .chain(keys.iter().map(String::as_str))
+            .map(ConfigKey::from)
+            .collect();
+        self.find_span(&keys)
+    }
+
+    fn parse(&self) -> Result<ConfigTable, Vec<Diagnostic>> {
+        match self.format.parse_value(&self.content) {
+            Ok(ConfigValue::Table(table)) => Ok(table),
+            Ok(_) => Err(vec![
+                self.error("the config must be a table".to_string(), None)
+            ]),
+            Err(message) => {
+                // TOML syntax errors come with a span
+                let diagnostic = match self.format {
+                    ConfigFormat::Toml => check_toml_syntax(&self.path, &self.content),
+                    _ => None,
+                };
+                Err(vec![diagnostic.unwrap_or_else(|| self.error(message, None))])
+            }
+        }
+    }
+}
+
+fn get_include_paths(
+    main: &SourceFile,
+    root: &ConfigTable,
+) -> Result<Vec<PathBuf>, Vec<Diagnostic>> {
+    let Some(include) = root.get("include") else {
+        return Ok(Vec::new());
+    };
+    let error = |message: String| vec![main.error(message, main.find_root_span("include"))];
+    let Some(patterns) = include.as_array() else {
+        return Err(error(
+            "`include` must be an array of glob patterns".to_string(),
+        ));
+    };
+
+    let directory = main.path.parent().unwrap_or(Path::new(""));
+    let mut include_paths = Vec::new();
+    for pattern in patterns {
+        let Some(pattern) = pattern.as_str() else {
+            return Err(error(
+                "`include` must be an array of glob patterns".to_string(),
+            ));
+        };
+        let full_pattern = directory.join(pattern);
+        let matches = glob::glob(&full_pattern.to_string_lossy())
+            .map_err(|e| error(format!("invalid include pattern `{}`: {}", pattern, e)))?;
+        let mut matched_paths: Vec<PathBuf> = matches.filter_map(Result::ok).collect();
+        if matched_paths.is_empty() {
+            return Err(error(format!(
+                "include pattern `{}` matched no files",
+                pattern
+            )));
+        }
+        matched_paths.sort();
+        for matched_path in matched_paths {
+            if matched_path != main.path && !include_paths.contains(&matched_path) {
+                include_paths.push(matched_path);
+            }
+        }
+    }
+    Ok(include_paths)
+}
+
+// Merges the enums of an included file, recursing into namespaces that both files use
+fn merge_enums(
+    target: &mut ConfigTable,
+    source: ConfigTable,
+    keys: &[String],
+    duplicates: &mut Vec<Vec<String>>,
+) {
+    for (key, value) in source {
+        let mut key_path = keys.to_vec();
+        key_path.push(key.clone());
+        match (target.get_mut(&key), value) {
+            (None, value) => {
+                target.insert(key, value);
+            }
+            (Some(ConfigValue::Table(existing)), ConfigValue::Table(table))
+                if is_namespace(existing) && is_namespace(&table) =>
+            {
+                merge_enums(existing, table, &key_path, duplicates);
+            }
+            (Some(_), _) => duplicates.push(key_path),
+        }
+    }
+}
+
+// Included files may only add enums, every other setting belongs to the main config
+fn check_included_root(file: &SourceFile, root: &ConfigTable) -> Vec<Diagnostic> {
+    root.keys()
+        .filter(|key| *key != "enums")
+        .map(|key| {
+            file.error(
+                format!("`{}` can only be set in the main config", key),
+                file.find_root_span(key),
+            )
+            .with_hint("included files may only contain `enums`".to_string())
+        })
+        .collect()
+}
+
+// How many of the keys lead into the value, following tables by name and arrays by index
+fn get_key_depth(value: &ConfigValue, keys: &[ConfigKey]) -> usize {
+    let Some((key, rest)) = keys.split_first() else {
+        return 0;
+    };
+    let child = match (value, key) {
+        (ConfigValue::Table(table), ConfigKey::Name(name)) => table.get(name),
+        (ConfigValue::Array(array), ConfigKey::Index(index)) => array.get(*index),
+        _ => None,
+    };
+    child.map_or(0, |child| 1 + get_key_depth(child, rest))
+}
+
+fn has_key_path(table: &ConfigTable, keys: &[ConfigKey]) -> bool {
+    get_key_depth(&ConfigValue::Table(table.clone()), keys) == keys.len()
+}
+
+// Errors in enums belong to the file defining the enum, every other error to the main config
+fn locate_errors(
+    sources: &[(&SourceFile, ConfigTable)],
+    errors: &[ConfigError],
+) -> Vec<Diagnostic> {
+    errors
+        .iter()
+        .map(|error| {
+            let file = match error.keys.split_first() {
+                Some((ConfigKey::Name(key), enum_keys)) if key == "enums" => sources
+                    .iter()
+                    .max_by_key(|(_, enums)| {
+                        get_key_depth(&ConfigValue::Table(enums.clone()), enum_keys)
+                    })
+                    .map_or(sources[0].0, |(file, _)| *file),
+                _ => sources[0].0,
+            };
+            Diagnostic::from_config_error(error, &file.path, &file.content, file.format)
+        })
+        .collect()
+}
+
+fn take_enums(root: &mut ConfigTable) -> ConfigTable {
+    match root.shift_remove("enums") {
+        Some(ConfigValue::Table(enums)) => enums,
+        _ => ConfigTable::new(),
+    }
+}
+
+// Locked values are applied here, where the errors they cause can still be located in the sources
+fn build_config(
+    sources: &[(&SourceFile, ConfigTable)],
+    root: ConfigTable,
+    lock: Option<&mut ValueLock>,
+) -> Result<(EnumSetConfig, Vec<Diagnostic>), Vec<Diagnostic>> {
+    let (mut config, warnings) =
+        EnumSetConfig::from_table(root).map_err(|errors| locate_errors(sources, &errors))?;
+    if let Some(lock) = lock {
+        lock.apply(&mut config)
+            .map_err(|errors| locate_errors(sources, &errors))?;
+    }
+    Ok((config, locate_errors(sources, &warnings)))
+}
+
+// Parses the config at `path` along with every file matched by its `include` patterns
+pub fn load_config(
+    path: &Path,
+    content: &str,
+    format: ConfigFormat,
+) -> Result<(EnumSetConfig, Vec<Diagnostic>), Vec<Diagnostic>> {
+    load(path, content, format, None)
+}
+
+// Same as `load_config`, with the values of the lock applied to the config
+pub fn load_locked_config(
+    path: &Path,
+    content: &str,
+    format: ConfigFormat,
+    lock: &mut ValueLock,
+) -> Result<(EnumSetConfig, Vec<Diagnostic>), Vec<Diagnostic>> {
+    load(path, content, format, Some(lock))
+}
+
+fn load(
+    path: &Path,
+    content: &str,
+    format: ConfigFormat,
+    lock: Option<&mut ValueLock>,
+) -> Result<(EnumSetConfig, Vec<Diagnostic>), Vec<Diagnostic>> {
+    let main = SourceFile {
+        path: path.to_path_buf(),
+        content: content.to_string(),
+        format,
+    };
+    let mut root = main.parse()?;
+    let include_paths = get_include_paths(&main, &root)?;
+    if include_paths.is_empty() {
+        let enums = match root.get("enums") {
+            Some(ConfigValue::Table(enums)) => enums.clone(),
+            _ => ConfigTable::new(),
+        };
+        return build_config(&[(&main, enums)], root, lock);
+    }
+
+    let mut files = Vec::new();
+    for include_path in include_paths {
+        let Some(include_format) = ConfigFormat::from_path(&include_path) else {
+            return Err(vec![main.error(
+                format!("unknown config format of `{}`", include_path.display()),
+                main.find_root_span("include"),
+            )]);
+        };
+        match fs::read_to_string(&include_path) {
+            Ok(include_content) => files.push(SourceFile {
+                path: include_path,
+                content: include_content,
+                format: include_format,
+            }),
+            Err(error) => {
+                return Err(vec![main.error(
+                    format!("could not read `{}`: {}", include_path.display(), error),
+                    main.find_root_span("include"),
+                )])
+            }
+        }
+    }
+    let mut diagnostics = Vec::new();
+    let mut included_roots = Vec::new();
+    for file in files.iter() {
+        match file.parse() {
+            Ok(included_root) => included_roots.push(included_root),
+            Err(errors) => diagnostics.extend(errors),
+        }
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let mut enums = take_enums(&mut root);
+    for (file, included_root) in files.iter().zip(included_roots.iter()) {
+        diagnostics.extend(check_included_root(file, included_root));
+    }
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        return Err(diagnostics);
+    }
+
+    let mut sources = vec![(&main, enums.clone())];
+    for (file, mut included_root) in files.iter().zip(included_roots) {
+        let included_enums = take_enums(&mut included_root);
+        let mut duplicates = Vec::new();
+        merge_enums(&mut enums, included_enums.clone(), &[], &mut duplicates);
+        for keys in duplicates {
+            let key_path: Vec<ConfigKey> = keys
+                .iter()
+                .map(|key| ConfigKey::from(key.as_str()))
+                .collect();
+            let mut diagnostic = file.error(
+                format!("enum `{}` is defined more than once", keys.join(".")),
+                file.find_enum_span(&keys),
+            );
+            if let Some((origin, _)) = sources
+                .iter()
+                .find(|(_, source_enums)| has_key_path(source_enums, &key_path))
+            {
+                diagnostic = diagnostic.with_hint(format!(
+                    "it is already defined in {}",
+                    origin.path.display()
+                ));
+            }
+            diagnostics.push(diagnostic);
+        }
+        sources.push((file, included_enums));
+    }
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        return Err(diagnostics);
+    }
+
+    // every file may refer to enums defined by any other
+    root.insert("enums".to_string(), ConfigValue::Table(enums));
+    let (config, warnings) = build_config(&sources, root, lock)?;
+    diagnostics.extend(warnings);
+    Ok((config, diagnostics))
+}
+
+#[cfg(test)]
+pub mod include_test {
+    use super::*;
+    use std::env;
+
+    const HEADER: &str = r#"use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+include = ["enums/*.toml"]
+"#;
+
+    // Writes the files into a fresh directory and loads the main config from it
+    fn load(
+        name: &str,
+        main: &str,
+        included: &[(&str, &str)],
+    ) -> Result<(EnumSetConfig, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let directory = env::temp_dir().join(format!("pseudo-enum-include-{}", name));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("enums")).expect("Failed to create directory");
+        for (file_name, file_content) in included {
+            fs::write(directory.join("enums").join(file_name), file_content)
+                .expect("Failed to write file");
+        }
+        let path = directory.join("pseudo-enum.toml");
+        let content = format!("{}{}", HEADER, main);
+        fs::write(&path, &content).expect("Failed to write file");
+        load_config(&path, &content, ConfigFormat::Toml)
+    }
+
+    #[test]
+    fn merge_included_enums() {
+        let (config, _) = load(
+            "merge",
+            "[enums]\nMapType = [\"City\"]\n",
+            &[
+                ("tool.toml", "[enums]\nTool.ClassId = [\"BladeCutlass\"]\n"),
+                (
+                    "tool_event.toml",
+                    "[enums]\nTool.Event = { extends = \"Tool.ClassId\", items = [\"Equipped\"] }\n",
+                ),
+            ],
+        )
+        .expect("Failed to load config");
+        let names: Vec<String> = config.enums.iter().map(|e| e.get_full_name()).collect();
+        assert_eq!(names, vec!["MapType", "Tool.ClassId", "Tool.Event"]);
+    }
+
+    #[test]
+    fn report_duplicate_enums() {
+        let diagnostics = load(
+            "duplicate",
+            "[enums]\nMapType = [\"City\"]\n",
+            &[("maps.toml", "[enums]\nMapType = [\"Desert\"]\n")],
+        )
+        .expect_err("Duplicate enum passed");
+        assert_eq!(
+            diagnostics[0].message,
+            "enum `MapType` is defined more than once"
+        );
+        assert!(diagnostics[0].path.ends_with("maps.toml"));
+        assert_eq!(diagnostics[0].location.as_ref().map(|l| l.line), Some(2));
+        assert!(diagnostics[0]
+            .hint
+            .as_deref()
+            .is_some_and(|hint| hint.ends_with("pseudo-enum.toml")));
+    }
+
+    #[test]
+    fn check_every_format() {
+        let content = r#"{
+    "use_union_types_for_export": true,
+    "use_union_types_for_parameters": true,
+    "assign_static_strings": true,
+    "enums": { "MapType": { "City": 1, "PowerLab": { "value": 2, "deprecated": true } } }
+}"#;
+        let path = Path::new("pseudo-enum.json");
+        let (_, warnings) =
+            load_config(path, content, ConfigFormat::Json).expect("Failed to load config");
+        assert_eq!(warnings[0].message, "Item 'MapType.PowerLab' is deprecated");
+
+        let diagnostics = load_config(
+            path,
+            &content.replace("\"enums\"", "\"sortt\": \"alphabetical\", \"enums\""),
+            ConfigFormat::Json,
+        )
+        .expect_err("Unknown key passed");
+        assert_eq!(diagnostics[0].message, "The config has unknown key 'sortt'");
+        assert_eq!(diagnostics[0].hint.as_deref(), Some("did you mean `sort`?"));
+        assert!(diagnostics[0].location.is_none());
+    }
+
+    #[test]
+    fn locate_lock_errors() {
+        let mut lock: ValueLock =
+            toml::from_str("[MapType]\nCity = 1\nPowerLab = 300\n").expect("Failed to parse lock");
+        let content = format!(
+            "{}[enums]\nMapType = {{ value_type = \"u8\", items = [\"City\", \"PowerLab\"] }}\n",
+            HEADER.replace("include = [\"enums/*.toml\"]\n", "")
+        );
+        let diagnostics = load_locked_config(
+            Path::new("pseudo-enum.toml"),
+            &content,
+            ConfigFormat::Toml,
+            &mut lock,
+        )
+        .expect_err("Locked value passed");
+        assert_eq!(
+            diagnostics[0].message,
+            "Item 'MapType.PowerLab' has value 300, which does not fit in u8"
+        );
+        assert_eq!(diagnostics[0].location.as_ref().map(|l| l.line), Some(5));
+    }
+}
@@ -1,2 +1,10 @@
+pub mod diagnostic;
 pub mod document;
+pub mod field;
+pub mod format;
+pub mod include;
+pub mod localization;
+pub mod lock;
 pub mod model;
+pub mod naming;
+pub mod schema;
@@ -0,0 +1,77 @@
+use crate::model::{get_locale_ids, Enum, EnumSetConfig};
+
+pub const LOCALIZATION_COLUMNS: &[&str] = &["Key", "Source", "Context", "Example"];
+
+fn to_csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+// A Roblox LocalizationTable CSV with a row per item of every localized enum, keyed by `Enum.Item`
+pub fn to_localization_csv(config: &EnumSetConfig) -> String {
+    let enums: Vec<Enum> = config
+        .get_sorted_enums()
+        .into_iter()
+        .filter(|e| e.has_text())
+        .collect();
+    let locale_ids = get_locale_ids(&enums);
+
+    let mut header: Vec<&str> = LOCALIZATION_COLUMNS.to_vec();
+    header.extend(locale_ids.iter().map(String::as_str));
+    let mut rows = vec![header.join(",")];
+    for e in enums.iter() {
+        for item in e.items.iter() {
+            let mut row = vec![
+                to_csv_field(&format!("{}.{}", e.get_full_name(), item.name)),
+                to_csv_field(item.display.as_deref().unwrap_or(item.name.as_str())),
+                String::new(),
+                String::new(),
+            ];
+            for locale_id in locale_ids.iter() {
+                row.push(to_csv_field(
+                    item.text.get(locale_id).map_or("", String::as_str),
+                ));
+            }
+            rows.push(row.join(","));
+        }
+    }
+    rows.push(String::new());
+    rows.join("\n")
+}
+
+#[cfg(test)]
+pub mod localization_test {
+    use super::*;
+
+    #[test]
+    fn export_localization_csv() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = false
+[enums]
+RunMode = ["Dev", "Live"]
+
+[enums.MapType.City]
+value = 1
+text = { en = "City", de = "Stadt" }
+
+[enums.MapType.PowerLab]
+value = 2
+display = "Power Lab"
+text = { en = "Power, Lab", "pt-br" = "Laboratório" }
+"#,
+        )
+        .expect("Failed to parse toml");
+        assert_eq!(
+            to_localization_csv(&config),
+            "Key,Source,Context,Example,de,en,pt-br\n\
+             MapType.City,City,,,Stadt,City,\n\
+             MapType.PowerLab,Power Lab,,,,\"Power, Lab\",Laboratório\n"
+        );
+    }
+}
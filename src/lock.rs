@@ -0,0 +1,428 @@
+use crate::diagnostic::{join_keys, ConfigError};
+use crate::model::{Enum, EnumKind, EnumRelation, EnumSetConfig};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// Every value ever assigned to an item, keyed by enum name and then item name.
+// Entries are never removed so that values of deleted items are never handed out again.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ValueLock {
+    #[serde(flatten)]
+    pub enums: BTreeMap<String, BTreeMap<String, i64>>,
+}
+
+fn get_ancestors<'a>(e: &'a Enum, enums: &'a [Enum]) -> Vec<&'a Enum> {
+    let mut ancestors = Vec::new();
+    let mut current = e;
+    while let Some(parent) = &current.parent {
+        match enums.iter().find(|other| other.name == parent.name) {
+            Some(parent_enum) if !ancestors.contains(&parent_enum) => {
+                ancestors.push(parent_enum);
+                current = parent_enum;
+            }
+            _ => break,
+        }
+    }
+    ancestors
+}
+
+impl ValueLock {
+    // The values locked by the enum or by any enum extending it, so that
+    // new items of a parent never collide with items added by its children
+    fn get_used_values(&self, e: &Enum, enums: &[Enum]) -> Vec<i64> {
+        enums
+            .iter()
+            .filter(|other| {
+                other.name == e.name
+                    || get_ancestors(other, enums).iter().any(|ancestor| {
+                        ancestor.name == e.name
+                            && other.parent.as_ref().map(|parent| parent.relation)
+                                == Some(EnumRelation::Extends)
+                    })
+            })
+            .filter_map(|other| self.enums.get(&other.get_full_name()))
+            .flat_map(|locked| locked.values().copied())
+            .collect()
+    }
+
+    // Every enum is locked even if another one fails, so that all of their errors are reported
+    pub fn apply(&mut self, config: &mut EnumSetConfig) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        // enums renamed by naming conventions carry their locked values over
+        for e in config.enums.iter() {
+            if let Some(previous_name) = &e.previous_name {
+                if !self.enums.contains_key(&e.get_full_name()) {
+                    if let Some(locked) = self.enums.get(previous_name).cloned() {
+                        self.enums.insert(e.get_full_name(), locked);
+                    }
+                }
+            }
+        }
+
+        // parents are locked first so that children can copy their final values
+        let mut order: Vec<usize> = (0..config.enums.len()).collect();
+        order.sort_by_key(|i| get_ancestors(&config.enums[*i], &config.enums).len());
+
+        for i in order {
+            // string values are written out in the config, so there is nothing to lock
+            if config.enums[i].kind == EnumKind::String {
+                continue;
+            }
+            if let Some(parent) = config.enums[i].parent.clone() {
+                let Some(parent_enum) = config
+                    .enums
+                    .iter()
+                    .find(|other| other.name == parent.name)
+                    .cloned()
+                else {
+                    let e = &config.enums[i];
+                    errors.push(ConfigError::new(
+                        format!("Enum '{}' refers to unknown enum '{}'", e.name, parent.name),
+                        &join_keys(&e.keys, parent.relation.get_key()),
+                    ));
+                    continue;
+                };
+                for item in config.enums[i].items.iter_mut() {
+                    if let Some(parent_item) =
+                        parent_enum.items.iter().find(|p| p.name == item.name)
+                    {
+                        item.value = parent_item.value;
+                    }
+                }
+            }
+
+            let mut used_values = self.get_used_values(&config.enums[i], &config.enums);
+            let e = &mut config.enums[i];
+            let step = e.get_step();
+            let reserved = e.reserved.clone();
+            let is_reserved = |value: i64| reserved.iter().any(|range| range.contains(&value));
+            let locked = self.enums.entry(e.get_full_name()).or_default();
+
+            // explicit values in the config always win, but are remembered,
+            // unless the lock already handed the value to another item
+            let mut has_errors = false;
+            let previous_locked = locked.clone();
+            for item in e.items.iter().filter(|item| item.is_value_explicit) {
+                let owner = previous_locked.iter().find(|(name, value)| {
+                    **value == item.value
+                        && !std::iter::once(&item.name)
+                            .chain(item.aliases.iter())
+                            .chain(item.previous_names.iter())
+                            .any(|own_name| own_name.as_str() == name.as_str())
+                });
+                if let Some((owner, _)) = owner {
+                    let is_retired = !e.items.iter().any(|other| other.name.as_str() == owner);
+                    errors.push(
+                        ConfigError::new(
+                            format!(
+                                "Item '{}.{}' has value {}, which the lock gives to '{}.{}'",
+                                e.name, item.name, item.value, e.name, owner
+                            ),
+                            &item.keys,
+                        )
+                        .with_hint(if is_retired {
+                            format!(
+                                "'{}' was removed, and saved data may still hold its value, so pick another value",
+                                owner
+                            )
+                        } else {
+                            format!("saved data reads {} as '{}', so pick another value", item.value, owner)
+                        }),
+                    );
+                    has_errors = true;
+                    continue;
+                }
+                locked.insert(item.name.to_string(), item.value);
+                used_values.push(item.value);
+            }
+
+            for item in e.items.iter_mut().filter(|item| !item.is_value_explicit) {
+                // renamed items find their value under an alias or the name they were converted from
+                let locked_value = std::iter::once(&item.name)
+                    .chain(item.aliases.iter())
+                    .chain(item.previous_names.iter())
+                    .find_map(|name| locked.get(name.as_str()).copied());
+                if let Some(value) = locked_value {
+                    locked.insert(item.name.to_string(), value);
+                    item.value = value;
+                    continue;
+                }
+                // new items keep the value numbered from the config unless it was ever handed out
+                if !used_values.contains(&item.value) && !is_reserved(item.value) {
+                    locked.insert(item.name.to_string(), item.value);
+                    used_values.push(item.value);
+                    continue;
+                }
+                let max_value = used_values.iter().copied().max().unwrap_or(0);
+                let mut value = e.kind.get_next_value(max_value, step, e.value_type);
+                while let Some(reserved) = value.filter(|value| is_reserved(*value)) {
+                    value = e.kind.get_next_value(reserved, step, e.value_type);
+                }
+                let Some(value) = value else {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Enum '{}' has no values left to assign to '{}'",
+                            e.name, item.name
+                        ),
+                        &item.keys,
+                    ));
+                    has_errors = true;
+                    continue;
+                };
+                locked.insert(item.name.to_string(), value);
+                used_values.push(value);
+                item.value = value;
+            }
+
+            if has_errors {
+                continue;
+            }
+
+            let mut has_shared_values = false;
+            for (index, item) in e.items.iter().enumerate() {
+                if let Some(other) = e.items[..index].iter().find(|o| o.value == item.value) {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Items '{}.{}' and '{}.{}' share value {}, give one of them an explicit value",
+                            e.name, other.name, e.name, item.name, item.value
+                        ),
+                        &item.keys,
+                    ));
+                    has_shared_values = true;
+                }
+            }
+            // locked values may no longer fit a narrowed value type
+            if !has_shared_values {
+                errors.extend(e.check_values());
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod lock_test {
+    use super::*;
+    use toml;
+
+    fn build(config_str: &str, lock: &mut ValueLock) -> Vec<(String, i64)> {
+        let mut config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        lock.apply(&mut config).expect("Failed to apply lock");
+        config.enums[0]
+            .items
+            .iter()
+            .map(|item| (item.name.to_string(), item.value))
+            .collect()
+    }
+
+    const HEADER: &str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+"#;
+
+    #[test]
+    fn keep_values_stable() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!("{}MapType = [\"City\", \"PowerLab\"]", HEADER),
+            &mut lock,
+        );
+        let items = build(
+            &format!("{}MapType = [\"City\", \"Desert\", \"PowerLab\"]", HEADER),
+            &mut lock,
+        );
+        assert_eq!(
+            items,
+            vec![
+                ("City".to_string(), 1),
+                ("Desert".to_string(), 3),
+                ("PowerLab".to_string(), 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn never_reuse_removed_values() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!("{}MapType = [\"City\", \"PowerLab\"]", HEADER),
+            &mut lock,
+        );
+        let items = build(
+            &format!("{}MapType = [\"City\", \"Desert\"]", HEADER),
+            &mut lock,
+        );
+        assert_eq!(
+            items,
+            vec![("City".to_string(), 1), ("Desert".to_string(), 3)]
+        );
+
+        let serialized = toml::to_string(&lock).expect("Failed to serialize lock");
+        let parsed: ValueLock = toml::from_str(&serialized).expect("Failed to parse lock");
+        assert_eq!(parsed, lock);
+    }
+
+    #[test]
+    fn reject_explicit_values_of_removed_items() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!("{}Fruit = [\"Apple\", \"Pear\", \"Plum\"]", HEADER),
+            &mut lock,
+        );
+        let mut config: EnumSetConfig =
+            toml::from_str(&format!("{}Fruit = {{ Apple = 1, Kiwi = 2 }}", HEADER))
+                .expect("Failed to parse toml");
+        let errors = lock.apply(&mut config).expect_err("Reused value passed");
+        assert_eq!(
+            errors[0].message,
+            "Item 'Fruit.Kiwi' has value 2, which the lock gives to 'Fruit.Pear'"
+        );
+        assert_eq!(lock.enums["Fruit"]["Pear"], 2);
+        assert!(!lock.enums["Fruit"].contains_key("Kiwi"));
+    }
+
+    #[test]
+    fn keep_extended_values_apart() {
+        let mut lock = ValueLock::default();
+        let config_str = format!(
+            "{}EquipContext = [\"Right\", \"Left\"]\nDualEquipContext = {{ extends = \"EquipContext\", items = [\"Dual\"] }}",
+            HEADER
+        );
+        build(&config_str, &mut lock);
+        let items = build(
+            &config_str.replace("\"Left\"]", "\"Left\", \"Head\"]"),
+            &mut lock,
+        );
+        assert_eq!(
+            items,
+            vec![
+                ("Right".to_string(), 1),
+                ("Left".to_string(), 2),
+                ("Head".to_string(), 4)
+            ]
+        );
+        assert_eq!(lock.enums["DualEquipContext"]["Dual"], 3);
+        assert_eq!(lock.enums["DualEquipContext"]["Head"], 4);
+    }
+
+    #[test]
+    fn keep_numbered_values() {
+        let mut lock = ValueLock::default();
+        let items = build(
+            &format!(
+                "{}Weapon = {{ start = 100, step = 10, items = [\"Sword\", {{ name = \"Staff\", value = 150 }}, \"Bow\"] }}",
+                HEADER
+            ),
+            &mut lock,
+        );
+        assert_eq!(
+            items,
+            vec![
+                ("Sword".to_string(), 100),
+                ("Staff".to_string(), 150),
+                ("Bow".to_string(), 160)
+            ]
+        );
+        let items = build(
+            &format!(
+                "{}Weapon = {{ start = 100, step = 10, items = [\"Axe\", \"Sword\", {{ name = \"Staff\", value = 150 }}, \"Bow\"] }}",
+                HEADER
+            ),
+            &mut lock,
+        );
+        assert_eq!(items[0], ("Axe".to_string(), 170));
+        assert_eq!(items[1], ("Sword".to_string(), 100));
+    }
+
+    #[test]
+    fn skip_reserved_values() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!("{}MapType = [\"City\", \"PowerLab\"]", HEADER),
+            &mut lock,
+        );
+        let items = build(
+            &format!(
+                "{}MapType = {{ reserved = [\"2..3\"], reserved_names = [\"PowerLab\"], items = [\"City\", \"Desert\"] }}",
+                HEADER
+            ),
+            &mut lock,
+        );
+        assert_eq!(items[1], ("Desert".to_string(), 4));
+    }
+
+    #[test]
+    fn keep_values_of_renamed_items() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!("{}MapType = [\"City\", \"power_lab\"]", HEADER),
+            &mut lock,
+        );
+        let items = build(
+            &format!(
+                "naming = {{ items = \"PascalCase\", convert = true, keep_original = true }}\n{}MapType = [\"Desert\", \"City\", \"power_lab\"]",
+                HEADER
+            ),
+            &mut lock,
+        );
+        assert_eq!(items[2], ("PowerLab".to_string(), 2));
+    }
+
+    #[test]
+    fn keep_values_of_converted_names() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!("{}map_type = [\"City\", \"power_lab\"]", HEADER),
+            &mut lock,
+        );
+        let items = build(
+            &format!(
+                "naming = {{ items = \"PascalCase\", enums = \"PascalCase\", convert = true }}\n{}map_type = [\"Desert\", \"City\", \"power_lab\"]",
+                HEADER
+            ),
+            &mut lock,
+        );
+        assert_eq!(
+            items,
+            vec![
+                ("Desert".to_string(), 3),
+                ("City".to_string(), 1),
+                ("PowerLab".to_string(), 2)
+            ]
+        );
+        assert_eq!(lock.enums["MapType"]["PowerLab"], 2);
+    }
+
+    #[test]
+    fn allocate_flag_bits() {
+        let mut lock = ValueLock::default();
+        build(
+            &format!(
+                "{}Permission = {{ kind = \"flags\", items = [\"Read\", \"Write\"] }}",
+                HEADER
+            ),
+            &mut lock,
+        );
+        let items = build(
+            &format!(
+                "{}Permission = {{ kind = \"flags\", items = [\"Read\", \"Admin\", \"Write\"] }}",
+                HEADER
+            ),
+            &mut lock,
+        );
+        assert_eq!(
+            items,
+            vec![
+                ("Read".to_string(), 1),
+                ("Admin".to_string(), 4),
+                ("Write".to_string(), 2)
+            ]
+        );
+    }
+}
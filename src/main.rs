@@ -1,8 +1,15 @@
 use clap::{Parser, Subcommand};
+use lib::diagnostic::{Diagnostic, Severity};
 use lib::document::dump;
+use lib::format::ConfigFormat;
+use lib::include::{load_config, load_locked_config};
+use lib::localization::to_localization_csv;
+use lib::lock::ValueLock;
 use lib::model::EnumSetConfig;
+use lib::schema::get_schema;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process;
 
 #[derive(Parser)]
 #[command(name = "pseudo-enum", about = "A rust based tool for generating enums in luau.", long_about = None)]
@@ -18,32 +25,116 @@ enum CliCommand {
         config: Option<PathBuf>,
         #[arg(short = 'o', long)]
         out: Option<PathBuf>,
+        #[arg(short = 'l', long)]
+        lock: Option<PathBuf>,
+        #[arg(short = 'f', long)]
+        format: Option<ConfigFormat>,
     },
     Init,
+    Schema {
+        #[arg(short = 'o', long)]
+        out: Option<PathBuf>,
+    },
+    ExportLocalization {
+        #[arg(short = 'c', long)]
+        config: Option<PathBuf>,
+        #[arg(short = 'o', long)]
+        out: Option<PathBuf>,
+        #[arg(short = 'f', long)]
+        format: Option<ConfigFormat>,
+    },
 }
 
 pub const DEFAULT_TOML_STR: &str = r#"build_path = "src/Shared/Enums.luau"
 use_union_types_for_export = true
 use_union_types_for_parameters = true
 assign_static_strings = true
+sort = "declaration"
 [enums]
 "TestEnum" = ["Test1", "Test2"]
 "#;
 pub const DEFAULT_BUILD_PATH: &str = "src/Shared/Enums.luau";
 pub const DEFAULT_CONFIG_PATH: &str = "pseudo-enum.toml";
+pub const DEFAULT_LOCK_PATH: &str = "pseudo-enum.lock";
+
+// Prints every diagnostic and exits, as the config can not be built
+fn exit_with_diagnostics(diagnostics: &[Diagnostic]) -> ! {
+    for diagnostic in diagnostics.iter() {
+        eprintln!("{}\n", diagnostic);
+    }
+    eprintln!(
+        "error: could not build due to {} previous error(s)",
+        diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .count()
+    );
+    process::exit(1);
+}
+
+// Loads the config and its includes, exiting with every diagnostic if it is invalid
+fn read_config(
+    config: Option<PathBuf>,
+    format: Option<ConfigFormat>,
+    lock: Option<&mut ValueLock>,
+) -> EnumSetConfig {
+    let config_path = match config {
+        Some(path) => path,
+        None => PathBuf::from(DEFAULT_CONFIG_PATH),
+    };
+    let config_content = fs::read_to_string(&config_path).expect("Failed to read file");
+    let config_format = format
+        .or_else(|| ConfigFormat::from_path(&config_path))
+        .unwrap_or_default();
+    let result = match lock {
+        Some(lock) => load_locked_config(&config_path, &config_content, config_format, lock),
+        None => load_config(&config_path, &config_content, config_format),
+    };
+    match result {
+        Ok((config_set, warnings)) => {
+            for warning in warnings.iter() {
+                eprintln!("{}\n", warning);
+            }
+            if !warnings.is_empty() {
+                eprintln!("warning: the config has {} warning(s)\n", warnings.len());
+            }
+            config_set
+        }
+        Err(diagnostics) => exit_with_diagnostics(&diagnostics),
+    }
+}
+
+// A missing lock starts out empty, while a broken one is reported like an invalid config
+fn read_lock(lock_path: &Path) -> ValueLock {
+    let Ok(lock_content) = fs::read_to_string(lock_path) else {
+        return ValueLock::default();
+    };
+    toml::from_str(&lock_content).unwrap_or_else(|error: toml::de::Error| {
+        exit_with_diagnostics(&[Diagnostic::error(
+            error.message().to_string(),
+            lock_path,
+            &lock_content,
+            error.span(),
+        )])
+    })
+}
 
 fn main() {
     let args: Args = Args::parse();
 
     match args.command {
-        Some(CliCommand::Build { config, out }) => {
-            let config_path = match config {
+        Some(CliCommand::Build {
+            config,
+            out,
+            lock,
+            format,
+        }) => {
+            let lock_path = match lock {
                 Some(path) => path,
-                None => PathBuf::from(DEFAULT_CONFIG_PATH),
+                None => PathBuf::from(DEFAULT_LOCK_PATH),
             };
-            let config_content = fs::read_to_string(config_path).expect("Failed to read file");
-            let config_set: EnumSetConfig =
-                toml::from_str(&config_content).expect("Failed to parse toml");
+            let mut value_lock = read_lock(&lock_path);
+            let config_set = read_config(config, format, Some(&mut value_lock));
 
             let out_path = match out {
                 Some(path) => path,
@@ -52,12 +143,44 @@ fn main() {
                     .build_path
                     .unwrap_or(PathBuf::from(DEFAULT_BUILD_PATH)),
             };
-            fs::write(out_path, dump(config_set)).expect("Failed to write to file");
+            let code = match dump(config_set) {
+                Ok(code) => code,
+                Err(error) => {
+                    eprintln!("error: {}", error.replace('\n', "\nerror: "));
+                    process::exit(1);
+                }
+            };
+            // the lock only remembers values once they made it into a build
+            fs::write(
+                &lock_path,
+                toml::to_string(&value_lock).expect("Failed to serialize lock"),
+            )
+            .expect("Failed to write to file");
+            fs::write(out_path, code).expect("Failed to write to file");
         }
         Some(CliCommand::Init) => {
             fs::write(PathBuf::from(DEFAULT_CONFIG_PATH), DEFAULT_TOML_STR)
                 .expect("Failed to write to file");
         }
+        Some(CliCommand::Schema { out }) => {
+            let schema =
+                serde_json::to_string_pretty(&get_schema()).expect("Failed to serialize schema");
+            match out {
+                Some(path) => fs::write(path, schema).expect("Failed to write to file"),
+                None => println!("{}", schema),
+            }
+        }
+        Some(CliCommand::ExportLocalization {
+            config,
+            out,
+            format,
+        }) => {
+            let csv = to_localization_csv(&read_config(config, format, None));
+            match out {
+                Some(path) => fs::write(path, csv).expect("Failed to write to file"),
+                None => print!("{}", csv),
+            }
+        }
         None => {
             panic!("No subcommand provided.");
         }
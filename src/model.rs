@@ -1,27 +1,129 @@
-use serde::{Deserialize, Deserializer, Serialize};
-use std::collections::{HashMap, HashSet};
+use crate::diagnostic::{
+    get_suggestion_hint, has_errors, join_errors, join_keys, unknown_key, ConfigError, ConfigKey,
+    KNOWN_KEYS,
+};
+use crate::field::{EnumField, FieldType, FieldValue};
+use crate::format::{ConfigTable, ConfigValue};
+use crate::naming::NamingConfig;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 use std::str::FromStr;
+pub const LUAU_KEYWORDS: &[&str] = &[
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "if", "in", "local",
+    "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LuauNameError {
+    Empty,
+    InvalidStart { name: String, character: char },
+    InvalidCharacter { name: String, character: char },
+    ReservedKeyword { name: String },
+}
+
+impl LuauNameError {
+    pub fn hint(&self) -> String {
+        match self {
+            LuauNameError::Empty => "names need at least one character".to_string(),
+            LuauNameError::InvalidStart { .. } => {
+                "names must start with an ASCII letter or '_'".to_string()
+            }
+            LuauNameError::InvalidCharacter { .. } => {
+                "names may only contain ASCII letters, digits and '_'".to_string()
+            }
+            LuauNameError::ReservedKeyword { name } => {
+                format!("try a different spelling, such as '{}_'", name)
+            }
+        }
+    }
+}
+
+impl fmt::Display for LuauNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LuauNameError::Empty => write!(f, "name is empty"),
+            LuauNameError::InvalidStart { name, character } => {
+                write!(f, "name '{}' cannot start with '{}'", name, character)
+            }
+            LuauNameError::InvalidCharacter { name, character } => {
+                write!(f, "name '{}' has bad character '{}'", name, character)
+            }
+            LuauNameError::ReservedKeyword { name } => {
+                write!(f, "name '{}' is a reserved Luau keyword", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LuauNameError {}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String")]
 pub struct LuauName(String);
 
 impl LuauName {
-    pub fn new(input: &str) -> Result<Self, String> {
-        if !input.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            return Err(format!("EnumValue '{}' has bad character", input));
+    pub fn new(input: &str) -> Result<Self, LuauNameError> {
+        let mut chars = input.chars();
+        let Some(first) = chars.next() else {
+            return Err(LuauNameError::Empty);
+        };
+        if !(first.is_ascii_alphabetic() || first == '_') {
+            return Err(LuauNameError::InvalidStart {
+                name: input.to_string(),
+                character: first,
+            });
+        }
+        if let Some(character) = chars.find(|c| !(c.is_ascii_alphanumeric() || *c == '_')) {
+            return Err(LuauNameError::InvalidCharacter {
+                name: input.to_string(),
+                character,
+            });
+        }
+        if LUAU_KEYWORDS.contains(&input) {
+            return Err(LuauNameError::ReservedKeyword {
+                name: input.to_string(),
+            });
         }
 
         Ok(LuauName(input.to_string()))
     }
 
+    // Splits a dotted enum name like `Tool.ClassId` into its segments
+    pub fn parse_path(input: &str) -> Result<Vec<Self>, LuauNameError> {
+        input.split('.').map(LuauName::new).collect()
+    }
+
+    // The type name of a dotted enum name, made of its segments joined together
+    pub fn from_path(input: &str) -> Result<Self, LuauNameError> {
+        let segments = LuauName::parse_path(input)?;
+        LuauName::new(
+            &segments
+                .iter()
+                .map(LuauName::as_str)
+                .collect::<Vec<&str>>()
+                .concat(),
+        )
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
 }
 
+impl TryFrom<String> for LuauName {
+    type Error = LuauNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        LuauName::new(&value)
+    }
+}
+
 impl FromStr for LuauName {
-    type Err = String;
+    type Err = LuauNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         LuauName::new(s)
@@ -37,101 +139,1940 @@ impl fmt::Display for LuauName {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct EnumItem {
     pub name: LuauName,
-    pub value: u16,
+    // The position of the item in string enums, which only expose `string_value`
+    pub value: i64,
+    #[serde(skip)]
+    pub is_value_explicit: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub string_value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub data: BTreeMap<String, FieldValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<LuauName>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    // Localized display text keyed by Roblox locale id, e.g. `en` or `pt-br`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub text: BTreeMap<String, String>,
+    // Names the item had before naming conventions converted it, which the value lock still knows
+    #[serde(skip)]
+    pub(crate) previous_names: Vec<LuauName>,
+    // The keys the item was read from, which locate problems found after parsing
+    #[serde(skip)]
+    pub(crate) keys: Vec<ConfigKey>,
+}
+
+impl EnumItem {
+    // Lays the metadata the config gives `other` over this item, keeping whatever it leaves out
+    fn merge_metadata(&mut self, other: EnumItem) {
+        if other.display.is_some() {
+            self.display = other.display;
+        }
+        if other.description.is_some() {
+            self.description = other.description;
+        }
+        if !other.tags.is_empty() {
+            self.tags = other.tags;
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated;
+        }
+        self.data.extend(other.data);
+        self.text.extend(other.text);
+        for alias in other.aliases {
+            if !self.aliases.contains(&alias) {
+                self.aliases.push(alias);
+            }
+        }
+        self.keys = other.keys;
+    }
+
+    pub fn new(name: LuauName, value: i64, is_value_explicit: bool) -> Self {
+        EnumItem {
+            name,
+            value,
+            is_value_explicit,
+            string_value: None,
+            display: None,
+            description: None,
+            tags: Vec::new(),
+            data: BTreeMap::new(),
+            aliases: Vec::new(),
+            deprecated: None,
+            text: BTreeMap::new(),
+            previous_names: Vec::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    pub fn has_metadata(&self) -> bool {
+        self.display.is_some() || self.description.is_some() || !self.tags.is_empty()
+    }
+
+    pub fn has_documentation(&self) -> bool {
+        self.has_metadata() || self.deprecated.is_some()
+    }
+
+    // The value as written into the generated script
+    pub fn get_luau_value(&self) -> String {
+        match &self.string_value {
+            Some(text) => crate::document::to_luau_string(text),
+            None => self.value.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub struct Enum {
     pub name: LuauName,
+    // Only set for namespaced enums, whose type name joins the segments together
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<LuauName>,
     pub items: Vec<EnumItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EnumField>,
+    #[serde(default)]
+    pub kind: EnumKind,
+    #[serde(skip)]
+    pub is_kind_explicit: bool,
+    #[serde(default)]
+    pub value_type: ValueType,
+    #[serde(skip)]
+    pub is_value_type_explicit: bool,
+    // Where automatic numbering starts and how far apart it spaces items, both 1 by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<i64>,
+    // The item to fall back to when a value is missing or unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<LuauName>,
+    // Values and names of removed items, which old saves may still hold
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reserved: Vec<RangeInclusive<i64>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reserved_names: Vec<LuauName>,
+    // Old names and values from `[migrations]`, mapped to the items they became
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub migrations: Vec<EnumMigration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<EnumParent>,
+    // The full name before naming conventions converted it, which the value lock still knows
+    #[serde(skip)]
+    pub(crate) previous_name: Option<String>,
+    #[serde(skip)]
+    pub(crate) keys: Vec<ConfigKey>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
-enum RawValueData {
-    Array(Vec<LuauName>),
-    Dictionary(HashMap<LuauName, u16>),
-}
-
-#[derive(Debug, Deserialize)]
-struct EnumsTable {
-    #[serde(flatten)]
-    enums: HashMap<String, toml::Value>,
-}
-
-fn raw_enums_to_enum_set<'de, D>(deser: D) -> Result<HashSet<Enum>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    // First, parse `[enums]` into a HashMap of `String -> toml::Value`.
-    let table = EnumsTable::deserialize(deser)?;
-
-    let mut enums = HashSet::new();
-
-    for (key, value) in table.enums {
-        let enum_name = LuauName::new(&key).map_err(serde::de::Error::custom)?;
-
-        let items = match value {
-            // If it's an array (e.g. Letters = ["A", "B", "C"])
-            toml::Value::Array(arr) => {
-                arr.into_iter()
-                    .enumerate()
-                    .map(|(i, val)| {
-                        // Convert each element to a string, then to LuauName
-                        let s = val
-                            .as_str()
-                            .ok_or_else(|| serde::de::Error::custom("Expected string in array"))?;
-                        let name = LuauName::new(s).map_err(serde::de::Error::custom)?;
-                        Ok(EnumItem {
-                            name,
-                            value: (i as u16) + 1,
-                        })
-                    })
-                    .collect::<Result<Vec<_>, _>>()?
-            }
-
-            // If it's a table (e.g. [enums.Device] subtable)
-            toml::Value::Table(tbl) => {
-                let mut items = Vec::new();
-                for (k, v) in tbl {
-                    let name = LuauName::new(&k).map_err(serde::de::Error::custom)?;
-                    let value = v
-                        .as_integer()
-                        .ok_or_else(|| serde::de::Error::custom("Expected integer in table"))?;
-                    items.push(EnumItem {
-                        name,
-                        value: value as u16,
-                    });
-                }
-                // Sort items by their `value`
-                items.sort_by_key(|item| item.value);
-                items
-            }
-
-            // Otherwise, unexpected type
+impl Enum {
+    pub fn new(name: LuauName, items: Vec<EnumItem>) -> Self {
+        Enum {
+            name,
+            path: Vec::new(),
+            items,
+            fields: Vec::new(),
+            kind: EnumKind::default(),
+            is_kind_explicit: false,
+            value_type: ValueType::default(),
+            is_value_type_explicit: false,
+            start: None,
+            step: None,
+            default: None,
+            reserved: Vec::new(),
+            reserved_names: Vec::new(),
+            migrations: Vec::new(),
+            parent: None,
+            previous_name: None,
+            keys: Vec::new(),
+        }
+    }
+
+    // The segments of the dotted name, e.g. `Tool` and `ClassId` for `Tool.ClassId`
+    pub fn get_path(&self) -> Vec<LuauName> {
+        if self.path.is_empty() {
+            vec![self.name.clone()]
+        } else {
+            self.path.clone()
+        }
+    }
+
+    pub fn get_full_name(&self) -> String {
+        self.get_path()
+            .iter()
+            .map(LuauName::as_str)
+            .collect::<Vec<&str>>()
+            .join(".")
+    }
+
+    // A field type naming the enum would read as the built-in type
+    pub fn check_name(&self) -> Option<ConfigError> {
+        let name = self.get_full_name();
+        let is_built_in = FieldType::BUILT_IN
+            .iter()
+            .any(|field_type| field_type.to_string() == name);
+        is_built_in.then(|| {
+            ConfigError::new(
+                format!("Enum '{}' has the name of a built-in field type", name),
+                &self.keys,
+            )
+            .at_key()
+            .with_hint(format!("rename it, e.g. to `{}Kind`", self.name))
+        })
+    }
+
+    pub fn check_item_names(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        for (index, item) in self.items.iter().enumerate() {
+            if let Some(first) = self.items[..index]
+                .iter()
+                .position(|other| other.name == item.name)
+            {
+                errors.push(
+                    ConfigError::new(
+                        format!(
+                            "Enum '{}' has more than one item named '{}'",
+                            self.name, item.name
+                        ),
+                        &item.keys,
+                    )
+                    .with_hint(format!(
+                        "`{}` is already item #{}",
+                        item.name,
+                        first + 1
+                    )),
+                );
+            }
+        }
+        errors
+    }
+
+    // Aliases share the namespace of the item names they resolve to
+    pub fn check_aliases(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        let mut names: Vec<&LuauName> = self.items.iter().map(|item| &item.name).collect();
+        for item in self.items.iter() {
+            for alias in item.aliases.iter() {
+                if names.contains(&alias) {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Alias '{}' of '{}.{}' is already used by another item or alias",
+                            alias, self.name, item.name
+                        ),
+                        &join_keys(&item.keys, "aliases"),
+                    ));
+                }
+                names.push(alias);
+            }
+        }
+        errors
+    }
+
+    pub fn check_default(&self) -> Option<ConfigError> {
+        let default = self.default.as_ref()?;
+        if self.items.iter().any(|item| item.name == *default) {
+            return None;
+        }
+        let names: Vec<&str> = self.items.iter().map(|item| item.name.as_str()).collect();
+        let mut error = ConfigError::new(
+            format!(
+                "Default '{}' of '{}' is not one of its items",
+                default, self.name
+            ),
+            &join_keys(&self.keys, "default"),
+        );
+        error.hint = get_suggestion_hint(default.as_str(), &names);
+        Some(error)
+    }
+
+    // Maps an old name or value onto the item it was renamed or renumbered to
+    pub fn add_migration(&mut self, old: &str, new: &str) -> Result<(), String> {
+        let target = self
+            .items
+            .iter()
+            .find(|item| {
+                item.name.as_str() == new || item.aliases.iter().any(|a| a.as_str() == new)
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Migration '{}' of '{}' refers to unknown item '{}'",
+                    old, self.name, new
+                )
+            })?;
+        let from = match old.parse::<i64>() {
+            Ok(value) if self.kind != EnumKind::String => {
+                if let Some(item) = self.items.iter().find(|item| item.value == value) {
+                    return Err(format!(
+                        "Migration '{}' of '{}' moves the value of '{}', which still exists",
+                        old, self.name, item.name
+                    ));
+                }
+                MigrationKey::Value(value)
+            }
             _ => {
-                return Err(serde::de::Error::custom(
-                    "Expected array or table for enum definition",
-                ));
+                let is_current = self.items.iter().any(|item| {
+                    item.name.as_str() == old
+                        || item.aliases.iter().any(|alias| alias.as_str() == old)
+                        || item.string_value.as_deref() == Some(old)
+                });
+                if is_current {
+                    return Err(format!(
+                        "Migration '{}' of '{}' renames an item that still exists",
+                        old, self.name
+                    ));
+                }
+                MigrationKey::Name(old.to_string())
             }
         };
+        self.migrations.push(EnumMigration {
+            from,
+            to: target.name.clone(),
+        });
+        Ok(())
+    }
 
-        enums.insert(Enum {
-            name: enum_name,
-            items,
+    // Flags always start at the first bit
+    pub fn get_start(&self) -> i64 {
+        match self.kind {
+            EnumKind::Flags => 1,
+            EnumKind::Standard | EnumKind::String => self.start.unwrap_or(1),
+        }
+    }
+
+    pub fn get_step(&self) -> i64 {
+        self.step.unwrap_or(1)
+    }
+
+    // Numbers items in declaration order, continuing after each explicit value and
+    // skipping values that are already taken
+    pub fn assign_auto_values(&mut self) -> Result<(), ConfigError> {
+        let step = self.get_step();
+        let mut used_values: Vec<i64> = self
+            .items
+            .iter()
+            .filter(|item| item.is_value_explicit)
+            .map(|item| item.value)
+            .collect();
+        let mut next_value = Some(self.get_start());
+        for i in 0..self.items.len() {
+            if self.items[i].is_value_explicit {
+                let after = self
+                    .kind
+                    .get_next_value(self.items[i].value, step, self.value_type);
+                next_value = next_value.zip(after).map(|(next, after)| next.max(after));
+                continue;
+            }
+            let mut value = next_value;
+            while let Some(taken) =
+                value.filter(|value| used_values.contains(value) || self.is_value_reserved(*value))
+            {
+                value = self.kind.get_next_value(taken, step, self.value_type);
+            }
+            let value = value.ok_or_else(|| {
+                ConfigError::new(
+                    format!(
+                        "Enum '{}' has no values left to assign to '{}'",
+                        self.name, self.items[i].name
+                    ),
+                    &self.items[i].keys,
+                )
+                .with_hint(format!("use a wider `value_type` than {}", self.value_type))
+            })?;
+            self.items[i].value = value;
+            used_values.push(value);
+            next_value = self.kind.get_next_value(value, step, self.value_type);
+        }
+        if self.kind == EnumKind::String {
+            for item in self.items.iter_mut().filter(|item| !item.is_value_explicit) {
+                if item.string_value.is_none() {
+                    item.string_value = Some(item.name.to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Checks the values of every item against the kind of the enum
+    pub fn check_values(&self) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+        if self.kind != EnumKind::Standard && (self.start.is_some() || self.step.is_some()) {
+            errors.push(ConfigError::new(
+                format!(
+                    "Enum '{}' sets a start or step, which only apply to standard enums",
+                    self.name
+                ),
+                &self.keys,
+            ));
+        }
+        errors.extend(self.check_reserved());
+        for (index, item) in self.items.iter().enumerate() {
+            let error = |message: String| ConfigError::new(message, &item.keys);
+            match (self.kind, &item.string_value) {
+                (EnumKind::String, Some(text)) => {
+                    if let Some(other) = self.items[..index]
+                        .iter()
+                        .find(|other| other.string_value.as_ref() == Some(text))
+                    {
+                        errors.push(error(format!(
+                            "Items '{}.{}' and '{}.{}' share value \"{}\"",
+                            self.name, other.name, self.name, item.name, text
+                        )));
+                    }
+                }
+                (EnumKind::String, None) if item.is_value_explicit => {
+                    errors.push(
+                        error(format!(
+                            "Item '{}.{}' needs a string value, as '{}' is a string enum",
+                            self.name, item.name, self.name
+                        ))
+                        .with_hint("string enums only take string values".to_string()),
+                    );
+                }
+                (EnumKind::String, None) => {}
+                (_, Some(_)) => {
+                    errors.push(error(format!(
+                        "Item '{}.{}' has a string value, but '{}' is not a string enum",
+                        self.name, item.name, self.name
+                    )));
+                }
+                (EnumKind::Flags | EnumKind::Standard, None) => {
+                    if let Some(range_error) = self.check_value_range(item) {
+                        errors.push(range_error);
+                        continue;
+                    }
+                    if self.kind == EnumKind::Flags
+                        && item.is_value_explicit
+                        && !is_single_bit(item.value)
+                    {
+                        errors.push(
+                            error(format!(
+                                "Flag '{}.{}' has value {}, which is not a single bit",
+                                self.name, item.name, item.value
+                            ))
+                            .with_hint(
+                                "flag values must be powers of two, such as 1, 2, 4 or 8"
+                                    .to_string(),
+                            ),
+                        );
+                    }
+                    if let Some(other) = self.items[..index]
+                        .iter()
+                        .find(|other| other.value == item.value)
+                    {
+                        errors.push(error(format!(
+                            "Items '{}.{}' and '{}.{}' share value {}",
+                            self.name, other.name, self.name, item.name, item.value
+                        )));
+                    }
+                }
+            }
+        }
+        errors
+    }
+
+    pub fn is_value_reserved(&self, value: i64) -> bool {
+        self.reserved.iter().any(|range| range.contains(&value))
+    }
+
+    // No item may take the value or name of a removed item
+    fn check_reserved(&self) -> Vec<ConfigError> {
+        if self.kind == EnumKind::String && !self.reserved.is_empty() {
+            return vec![ConfigError::new(
+                format!(
+                    "Enum '{}' is a string enum, so it can only reserve names",
+                    self.name
+                ),
+                &join_keys(&self.keys, "reserved"),
+            )];
+        }
+        let mut errors = Vec::new();
+        for item in self.items.iter() {
+            if self.kind != EnumKind::String && self.is_value_reserved(item.value) {
+                errors.push(ConfigError::new(
+                    format!(
+                        "Item '{}.{}' uses reserved value {}",
+                        self.name, item.name, item.value
+                    ),
+                    &item.keys,
+                ));
+            }
+            if let Some(name) = std::iter::once(&item.name)
+                .chain(item.aliases.iter())
+                .find(|name| self.reserved_names.contains(name))
+            {
+                errors.push(ConfigError::new(
+                    format!(
+                        "Item '{}.{}' uses reserved name '{}'",
+                        self.name, item.name, name
+                    ),
+                    &item.keys,
+                ));
+            }
+        }
+        errors
+    }
+
+    fn check_value_range(&self, item: &EnumItem) -> Option<ConfigError> {
+        if self.value_type.contains(item.value) {
+            return None;
+        }
+        let (min, max) = self.value_type.get_range();
+        Some(
+            ConfigError::new(
+                format!(
+                    "Item '{}.{}' has value {}, which does not fit in {}",
+                    self.name, item.name, item.value, self.value_type
+                ),
+                &item.keys,
+            )
+            .with_hint(format!(
+                "{} values range from {} to {}",
+                self.value_type, min, max
+            )),
+        )
+    }
+
+    // Items sorted by value, which for string enums is their declaration order
+    pub fn get_ordered_items(&self) -> Vec<&EnumItem> {
+        let mut items: Vec<&EnumItem> = self.items.iter().collect();
+        items.sort_by_key(|item| item.value);
+        items
+    }
+
+    pub fn get_data_type_name(&self) -> String {
+        format!("{}Data", self.name)
+    }
+
+    pub fn has_metadata(&self) -> bool {
+        self.items.iter().any(|item| item.has_metadata())
+    }
+
+    pub fn has_text(&self) -> bool {
+        self.items.iter().any(|item| !item.text.is_empty())
+    }
+
+    pub fn has_documentation(&self) -> bool {
+        self.items.iter().any(|item| item.has_documentation())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EnumKind {
+    #[default]
+    Standard,
+    Flags,
+    String,
+}
+
+impl fmt::Display for EnumKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnumKind::Standard => write!(f, "standard"),
+            EnumKind::Flags => write!(f, "flags"),
+            EnumKind::String => write!(f, "string"),
+        }
+    }
+}
+
+impl EnumKind {
+    pub const ALL: &'static [EnumKind] = &[EnumKind::Standard, EnumKind::Flags, EnumKind::String];
+}
+
+// Roblox locale ids are a lowercase language code, optionally followed by a region
+pub fn is_locale_id(locale_id: &str) -> bool {
+    let mut parts = locale_id.split('-');
+    let language = parts.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && parts.all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+// A reserved value, either a single integer or an inclusive range written as "7..9"
+pub fn parse_value_range(value: &ConfigValue) -> Option<RangeInclusive<i64>> {
+    match value {
+        ConfigValue::Integer(value) => Some(*value..=*value),
+        ConfigValue::String(text) => parse_range_str(text),
+        _ => None,
+    }
+}
+
+pub fn parse_range_str(text: &str) -> Option<RangeInclusive<i64>> {
+    let (start, end) = text.split_once("..")?;
+    let (start, end) = (start.trim().parse().ok()?, end.trim().parse().ok()?);
+    (start <= end).then_some(start..=end)
+}
+
+// Every locale any item of `enums` has text for, in alphabetical order
+pub fn get_locale_ids(enums: &[Enum]) -> Vec<String> {
+    let mut locale_ids: Vec<String> = enums
+        .iter()
+        .flat_map(|e| e.items.iter())
+        .flat_map(|item| item.text.keys().cloned())
+        .collect();
+    locale_ids.sort();
+    locale_ids.dedup();
+    locale_ids
+}
+
+// Flags are combined with `bit32`, so every flag must be one of its 32 bits
+pub fn is_single_bit(value: i64) -> bool {
+    value > 0 && value <= u32::MAX as i64 && (value as u64).is_power_of_two()
+}
+
+impl EnumKind {
+    // The value numbered after `value`, if it still fits
+    pub fn get_next_value(&self, value: i64, step: i64, value_type: ValueType) -> Option<i64> {
+        match self {
+            // string enums only number their items to keep them in order
+            EnumKind::String => value.checked_add(1),
+            EnumKind::Standard => value
+                .checked_add(step)
+                .filter(|value| value_type.contains(*value)),
+            EnumKind::Flags => (0..32)
+                .map(|bit| 1i64 << bit)
+                .find(|bit| *bit > value)
+                .filter(|bit| value_type.contains(*bit)),
+        }
+    }
+
+    // The Luau type of the values in the value dicts
+    pub fn get_luau_type(&self) -> &'static str {
+        match self {
+            EnumKind::String => "string",
+            EnumKind::Standard | EnumKind::Flags => "number",
+        }
+    }
+}
+
+// The integer type item values are checked against, `i53` being the integers a Luau number holds exactly
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueType {
+    U8,
+    #[default]
+    U16,
+    I32,
+    U32,
+    I53,
+}
+
+impl ValueType {
+    pub const ALL: &'static [ValueType] = &[
+        ValueType::U8,
+        ValueType::U16,
+        ValueType::I32,
+        ValueType::U32,
+        ValueType::I53,
+    ];
+
+    pub fn get_range(&self) -> (i64, i64) {
+        match self {
+            ValueType::U8 => (0, u8::MAX as i64),
+            ValueType::U16 => (0, u16::MAX as i64),
+            ValueType::I32 => (i32::MIN as i64, i32::MAX as i64),
+            ValueType::U32 => (0, u32::MAX as i64),
+            ValueType::I53 => (-(1 << 53) + 1, (1 << 53) - 1),
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let (min, max) = self.get_range();
+        (min..=max).contains(&value)
+    }
+}
+
+impl FromStr for ValueType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u8" => Ok(ValueType::U8),
+            "u16" => Ok(ValueType::U16),
+            "i32" => Ok(ValueType::I32),
+            "u32" => Ok(ValueType::U32),
+            "i53" => Ok(ValueType::I53),
+            _ => Err(format!(
+                "Unknown value type '{}', expected u8, u16, i32, u32 or i53",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueType::U8 => write!(f, "u8"),
+            ValueType::U16 => write!(f, "u16"),
+            ValueType::I32 => write!(f, "i32"),
+            ValueType::U32 => write!(f, "u32"),
+            ValueType::I53 => write!(f, "i53"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EnumRelation {
+    Extends,
+    SubsetOf,
+}
+
+impl EnumRelation {
+    // The enum option that sets up the relation
+    pub fn get_key(&self) -> &'static str {
+        match self {
+            EnumRelation::Extends => "extends",
+            EnumRelation::SubsetOf => "subset_of",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct EnumParent {
+    pub name: LuauName,
+    pub relation: EnumRelation,
+}
+
+// Saved data holds either the name or the value of an item
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum MigrationKey {
+    Value(i64),
+    Name(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct EnumMigration {
+    pub from: MigrationKey,
+    pub to: LuauName,
+}
+
+pub const ENUM_KEYS: &[&str] = &[
+    "items",
+    "fields",
+    "kind",
+    "value_type",
+    "start",
+    "step",
+    "default",
+    "reserved",
+    "reserved_names",
+    "extends",
+    "subset_of",
+];
+pub const ITEM_KEYS: &[&str] = &[
+    "value",
+    "display",
+    "description",
+    "tags",
+    "data",
+    "aliases",
+    "deprecated",
+    "text",
+];
+
+// Items have an integer value, or a string value in string enums
+fn parse_item_value(
+    item: &mut EnumItem,
+    enum_name: &str,
+    value: &ConfigValue,
+    keys: &[ConfigKey],
+) -> Result<(), ConfigError> {
+    match value {
+        ConfigValue::Integer(value) => {
+            item.value = *value;
+            item.is_value_explicit = true;
+            Ok(())
+        }
+        ConfigValue::String(text) => {
+            // the position is assigned later by `Enum::assign_auto_values`
+            item.string_value = Some(text.clone());
+            Ok(())
+        }
+        _ => Err(ConfigError::new(
+            format!(
+                "Item '{}.{}' needs an integer or string value, found {}",
+                enum_name,
+                item.name,
+                value.type_name()
+            ),
+            keys,
+        )),
+    }
+}
+
+// Parses the extended item syntax, e.g. `[enums.MapType.City]` with `value = 1` and `display = "Big City"`
+fn parse_item_table(
+    item: &mut EnumItem,
+    enum_name: &str,
+    table: &ConfigTable,
+    errors: &mut Vec<ConfigError>,
+) -> bool {
+    parse_item_keys(item, enum_name, table, errors);
+    let keys = item.keys.clone();
+    let Some(value) = table.get("value") else {
+        errors.push(
+            ConfigError::new(
+                format!("Item '{}.{}' is missing a value", enum_name, item.name),
+                &keys,
+            )
+            .at_key()
+            .with_hint("add `value = <integer>` to the item".to_string()),
+        );
+        return false;
+    };
+    parse_item_value(item, enum_name, value, &join_keys(&keys, "value"))
+        .map_err(|error| errors.push(error))
+        .is_ok()
+}
+
+// Parses a table in an array enum, e.g. `{ name = "B", value = 10 }`, which may leave out its value
+fn parse_item_entry(
+    enum_name: &str,
+    mut table: ConfigTable,
+    keys: &[ConfigKey],
+    errors: &mut Vec<ConfigError>,
+) -> Option<EnumItem> {
+    let position = keys.last().map_or(String::new(), |key| match key {
+        ConfigKey::Index(index) => format!("#{}", index + 1),
+        ConfigKey::Name(name) => name.clone(),
+    });
+    let name = match table.shift_remove("name") {
+        Some(ConfigValue::String(name)) => name,
+        Some(other) => {
+            errors.push(ConfigError::new(
+                format!(
+                    "Item {} of '{}' needs a string name, found {}",
+                    position,
+                    enum_name,
+                    other.type_name()
+                ),
+                &join_keys(keys, "name"),
+            ));
+            return None;
+        }
+        None => {
+            errors.push(
+                ConfigError::new(
+                    format!("Item {} of '{}' is missing a name", position, enum_name),
+                    keys,
+                )
+                .with_hint("add `name = \"Item\"` to the table".to_string()),
+            );
+            return None;
+        }
+    };
+    let name = LuauName::new(&name)
+        .map_err(|error| {
+            errors.push(
+                ConfigError::new(error.to_string(), &join_keys(keys, "name"))
+                    .with_hint(error.hint()),
+            )
+        })
+        .ok()?;
+    let mut item = EnumItem::new(name, 0, false);
+    item.keys = keys.to_vec();
+    parse_item_keys(&mut item, enum_name, &table, errors);
+    // without a value, it is assigned later by `Enum::assign_auto_values`
+    if let Some(value) = table.get("value") {
+        parse_item_value(&mut item, enum_name, value, &join_keys(keys, "value"))
+            .map_err(|error| errors.push(error))
+            .ok()?;
+    }
+    Some(item)
+}
+
+fn parse_item_keys(
+    item: &mut EnumItem,
+    enum_name: &str,
+    table: &ConfigTable,
+    errors: &mut Vec<ConfigError>,
+) {
+    let item_name = format!("{}.{}", enum_name, item.name);
+    for (key, value) in table {
+        let keys = join_keys(&item.keys, key.as_str());
+        let error = |message: String| ConfigError::new(message, &keys);
+        match key.as_str() {
+            "value" => {}
+            "display" | "description" => match value.as_str() {
+                Some(text) if key == "display" => item.display = Some(text.to_string()),
+                Some(text) => item.description = Some(text.to_string()),
+                None => errors.push(error(format!(
+                    "Item '{}' needs a string {}, found {}",
+                    item_name,
+                    key,
+                    value.type_name()
+                ))),
+            },
+            "tags" => {
+                let tags = value.as_array().and_then(|tags| {
+                    tags.iter()
+                        .map(|tag| tag.as_str().map(|tag| tag.to_string()))
+                        .collect::<Option<Vec<_>>>()
+                });
+                match tags {
+                    Some(tags) => item.tags = tags,
+                    None => errors.push(
+                        error(format!(
+                            "Item '{}' needs an array of string tags",
+                            item_name
+                        ))
+                        .with_hint("use `tags = [\"A\", \"B\"]`".to_string()),
+                    ),
+                }
+            }
+            "deprecated" => match value {
+                ConfigValue::String(reason) => item.deprecated = Some(reason.clone()),
+                ConfigValue::Boolean(true) => item.deprecated = Some(String::new()),
+                ConfigValue::Boolean(false) => item.deprecated = None,
+                _ => errors.push(error(format!(
+                    "Item '{}' needs a deprecation message or boolean, found {}",
+                    item_name,
+                    value.type_name()
+                ))),
+            },
+            "aliases" => {
+                let Some(aliases) = value.as_array() else {
+                    errors.push(
+                        error(format!(
+                            "Item '{}' needs an array of alias names",
+                            item_name
+                        ))
+                        .with_hint("use `aliases = [\"OldName\"]`".to_string()),
+                    );
+                    continue;
+                };
+                for (index, alias) in aliases.iter().enumerate() {
+                    let alias_keys = join_keys(&keys, index);
+                    match alias.as_str().map(LuauName::new) {
+                        Some(Ok(alias)) => item.aliases.push(alias),
+                        Some(Err(error)) => errors.push(
+                            ConfigError::new(error.to_string(), &alias_keys)
+                                .with_hint(error.hint()),
+                        ),
+                        None => errors.push(ConfigError::new(
+                            format!(
+                                "Item '{}' needs string aliases, found {}",
+                                item_name,
+                                alias.type_name()
+                            ),
+                            &alias_keys,
+                        )),
+                    }
+                }
+            }
+            "data" => {
+                let Some(data) = value.as_table() else {
+                    errors.push(
+                        error(format!("Item '{}' needs a table of data", item_name))
+                            .with_hint("use `data = { Damage = 10 }`".to_string()),
+                    );
+                    continue;
+                };
+                for (field_name, field_value) in data {
+                    match FieldValue::deserialize(field_value.clone()) {
+                        Ok(field_value) => {
+                            item.data.insert(field_name.clone(), field_value);
+                        }
+                        Err(_) => errors.push(ConfigError::new(
+                            format!(
+                                "Item '{}' has unsupported data '{}' for field '{}'",
+                                item_name, field_value, field_name
+                            ),
+                            &join_keys(&keys, field_name.as_str()),
+                        )),
+                    }
+                }
+            }
+            "text" => {
+                let Some(text) = value.as_table() else {
+                    errors.push(
+                        error(format!(
+                            "Item '{}' needs a table of localized text",
+                            item_name
+                        ))
+                        .with_hint("use `text = { en = \"City\", de = \"Stadt\" }`".to_string()),
+                    );
+                    continue;
+                };
+                for (locale_id, locale_text) in text {
+                    let locale_keys = join_keys(&keys, locale_id.as_str());
+                    if !is_locale_id(locale_id) {
+                        errors.push(
+                            ConfigError::new(
+                                format!(
+                                    "Item '{}' has text for invalid locale id '{}'",
+                                    item_name, locale_id
+                                ),
+                                &locale_keys,
+                            )
+                            .at_key()
+                            .with_hint(
+                                "use a Roblox locale id such as `en` or `pt-br`".to_string(),
+                            ),
+                        );
+                        continue;
+                    }
+                    match locale_text.as_str() {
+                        Some(locale_text) => {
+                            item.text.insert(locale_id.clone(), locale_text.to_string());
+                        }
+                        None => errors.push(ConfigError::new(
+                            format!(
+                                "Item '{}' needs a string for locale '{}', found {}",
+                                item_name,
+                                locale_id,
+                                locale_text.type_name()
+                            ),
+                            &locale_keys,
+                        )),
+                    }
+                }
+            }
+            _ => errors.push(unknown_key(
+                key,
+                &keys,
+                &format!("Item '{}'", item_name),
+                ITEM_KEYS,
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EnumSort {
+    Alphabetical,
+    #[default]
+    Declaration,
+}
+
+impl EnumSort {
+    pub const ALL: &'static [EnumSort] = &[EnumSort::Declaration, EnumSort::Alphabetical];
+}
+
+// Parses the items of an enum, or returns `None` if it is neither an array nor a table
+fn parse_items(
+    enum_name: &str,
+    value: ConfigValue,
+    keys: &[ConfigKey],
+    errors: &mut Vec<ConfigError>,
+) -> Option<Vec<EnumItem>> {
+    match value {
+        // If it's an array (e.g. Letters = ["A", "B", "C"])
+        ConfigValue::Array(arr) => {
+            let mut items = Vec::new();
+            for (index, val) in arr.into_iter().enumerate() {
+                let item_keys = join_keys(keys, index);
+                match val {
+                    // Pinned items keep their place, e.g. `{ name = "B", value = 10 }`
+                    ConfigValue::Table(entry) => {
+                        items.extend(parse_item_entry(enum_name, entry, &item_keys, errors));
+                    }
+                    ConfigValue::String(name) => match LuauName::new(&name) {
+                        Ok(name) => {
+                            // the value is assigned later by `Enum::assign_auto_values`
+                            let mut item = EnumItem::new(name, 0, false);
+                            item.keys = item_keys;
+                            items.push(item);
+                        }
+                        Err(error) => errors.push(
+                            ConfigError::new(error.to_string(), &item_keys).with_hint(error.hint()),
+                        ),
+                    },
+                    other => errors.push(
+                        ConfigError::new(
+                            format!(
+                                "Item #{} of '{}' needs to be a name or a table, found {}",
+                                index + 1,
+                                enum_name,
+                                other.type_name()
+                            ),
+                            &item_keys,
+                        )
+                        .with_hint(
+                            "array items must be quoted names or tables with a `name`".to_string(),
+                        ),
+                    ),
+                }
+            }
+            Some(items)
+        }
+
+        // If it's a table (e.g. [enums.Device] subtable)
+        ConfigValue::Table(tbl) => {
+            let mut items = Vec::new();
+            for (k, v) in tbl {
+                let item_keys = join_keys(keys, k.as_str());
+                let name = match LuauName::new(&k) {
+                    Ok(name) => name,
+                    Err(error) => {
+                        errors.push(
+                            ConfigError::new(error.to_string(), &item_keys)
+                                .at_key()
+                                .with_hint(error.hint()),
+                        );
+                        continue;
+                    }
+                };
+                let mut item = EnumItem::new(name, 0, false);
+                item.keys = item_keys.clone();
+                let is_valid = match v {
+                    ConfigValue::Table(item_table) => {
+                        parse_item_table(&mut item, enum_name, &item_table, errors)
+                    }
+                    _ => parse_item_value(&mut item, enum_name, &v, &item_keys)
+                        .map_err(|error| errors.push(error))
+                        .is_ok(),
+                };
+                if is_valid {
+                    items.push(item);
+                }
+            }
+            // Sort items by their `value`
+            items.sort_by_key(|item| item.value);
+            Some(items)
+        }
+
+        // Otherwise, unexpected type
+        _ => {
+            errors.push(
+                ConfigError::new(
+                    format!(
+                        "Enum '{}' needs an array or table of items, found {}",
+                        enum_name,
+                        value.type_name()
+                    ),
+                    keys,
+                )
+                .with_hint(
+                    "use `Name = [\"A\", \"B\"]` or `[enums.Name]` with `A = 1`".to_string(),
+                ),
+            );
+            None
+        }
+    }
+}
+
+// An enum table with an `items` key holds enum level options next to its items
+fn is_detailed_enum(table: &ConfigTable) -> bool {
+    table
+        .get("items")
+        .is_some_and(|items| items.is_array() || items.is_table())
+}
+
+fn parse_enum_options(e: &mut Enum, table: ConfigTable, errors: &mut Vec<ConfigError>) {
+    for (key, value) in table {
+        let keys = join_keys(&e.keys, key.as_str());
+        let error = |message: String| ConfigError::new(message, &keys);
+        match key.as_str() {
+            "fields" => {
+                let Some(fields) = value.as_table() else {
+                    errors.push(
+                        error(format!("Enum '{}' needs a table of fields", e.name))
+                            .with_hint("use `fields = { Damage = \"number\" }`".to_string()),
+                    );
+                    continue;
+                };
+                for (field_name, field_type) in fields {
+                    let field_keys = join_keys(&keys, field_name.as_str());
+                    let name = match LuauName::new(field_name) {
+                        Ok(name) => name,
+                        Err(error) => {
+                            errors.push(
+                                ConfigError::new(error.to_string(), &field_keys)
+                                    .at_key()
+                                    .with_hint(error.hint()),
+                            );
+                            continue;
+                        }
+                    };
+                    let field_type = match field_type.as_str() {
+                        Some(field_type) => FieldType::from_str(field_type),
+                        None => Err(format!("Field '{}' needs a type name", field_name)),
+                    };
+                    match field_type {
+                        Ok(field_type) => e.fields.push(EnumField { name, field_type }),
+                        Err(message) => errors.push(ConfigError::new(message, &field_keys)),
+                    }
+                }
+            }
+            "kind" => match EnumKind::deserialize(value) {
+                Ok(kind) => {
+                    e.kind = kind;
+                    e.is_kind_explicit = true;
+                }
+                Err(_) => errors.push(
+                    error(format!("Enum '{}' needs a kind", e.name))
+                        .with_hint("expected \"standard\", \"flags\" or \"string\"".to_string()),
+                ),
+            },
+            "value_type" => match value.as_str().map(ValueType::from_str) {
+                Some(Ok(value_type)) => {
+                    e.value_type = value_type;
+                    e.is_value_type_explicit = true;
+                }
+                Some(Err(message)) => errors.push(error(message)),
+                None => errors.push(error(format!("Enum '{}' needs a value type name", e.name))),
+            },
+            "start" => match value.as_integer() {
+                Some(start) => e.start = Some(start),
+                None => errors.push(error(format!("Enum '{}' needs an integer start", e.name))),
+            },
+            "step" => match value.as_integer().filter(|step| *step > 0) {
+                Some(step) => e.step = Some(step),
+                None => errors.push(
+                    error(format!("Enum '{}' needs a positive integer step", e.name))
+                        .with_hint("use `step = 10` to space items 10 apart".to_string()),
+                ),
+            },
+            "default" => match value.as_str().map(LuauName::new) {
+                Some(Ok(default)) => e.default = Some(default),
+                Some(Err(name_error)) => {
+                    errors.push(error(name_error.to_string()).with_hint(name_error.hint()))
+                }
+                None => errors.push(error(format!(
+                    "Enum '{}' needs the name of its default item",
+                    e.name
+                ))),
+            },
+            "reserved" => {
+                let hint = "use values like `3` or ranges like `\"7..9\"`";
+                let Some(values) = value.as_array() else {
+                    errors.push(
+                        error(format!(
+                            "Enum '{}' needs an array of reserved values",
+                            e.name
+                        ))
+                        .with_hint(hint.to_string()),
+                    );
+                    continue;
+                };
+                for (index, value) in values.iter().enumerate() {
+                    match parse_value_range(value) {
+                        Some(range) => e.reserved.push(range),
+                        None => errors.push(
+                            ConfigError::new(
+                                format!("Enum '{}' has invalid reserved value {}", e.name, value),
+                                &join_keys(&keys, index),
+                            )
+                            .with_hint(hint.to_string()),
+                        ),
+                    }
+                }
+            }
+            "reserved_names" => {
+                let Some(names) = value.as_array() else {
+                    errors.push(
+                        error(format!(
+                            "Enum '{}' needs an array of reserved names",
+                            e.name
+                        ))
+                        .with_hint("use `reserved_names = [\"Old\"]`".to_string()),
+                    );
+                    continue;
+                };
+                for (index, name) in names.iter().enumerate() {
+                    let name_keys = join_keys(&keys, index);
+                    match name.as_str().map(LuauName::new) {
+                        Some(Ok(name)) => e.reserved_names.push(name),
+                        Some(Err(error)) => errors.push(
+                            ConfigError::new(error.to_string(), &name_keys).with_hint(error.hint()),
+                        ),
+                        None => errors.push(ConfigError::new(
+                            format!(
+                                "Enum '{}' needs string reserved names, found {}",
+                                e.name,
+                                name.type_name()
+                            ),
+                            &name_keys,
+                        )),
+                    }
+                }
+            }
+            "extends" | "subset_of" => {
+                if e.parent.is_some() {
+                    errors.push(
+                        error(format!(
+                            "Enum '{}' can only extend or subset a single enum",
+                            e.name
+                        ))
+                        .at_key(),
+                    );
+                    continue;
+                }
+                let parent_name = match value.as_str().map(LuauName::from_path) {
+                    Some(Ok(parent_name)) => parent_name,
+                    Some(Err(name_error)) => {
+                        errors.push(error(name_error.to_string()).with_hint(name_error.hint()));
+                        continue;
+                    }
+                    None => {
+                        errors.push(error(format!("Enum '{}' needs a parent enum name", e.name)));
+                        continue;
+                    }
+                };
+                e.parent = Some(EnumParent {
+                    name: parent_name,
+                    relation: if key == "extends" {
+                        EnumRelation::Extends
+                    } else {
+                        EnumRelation::SubsetOf
+                    },
+                });
+            }
+            _ => errors.push(unknown_key(
+                &key,
+                &keys,
+                &format!("Enum '{}'", e.name),
+                ENUM_KEYS,
+            )),
+        }
+    }
+}
+
+// Copies the items of the parent into an extending enum, or the values of the parent into a subset
+fn resolve_parent(e: &mut Enum, parent: &Enum, relation: EnumRelation) -> Vec<ConfigError> {
+    // a child may repeat the kind and value type of its parent, but not change them
+    let mut errors = Vec::new();
+    if e.is_kind_explicit && e.kind != parent.kind {
+        errors.push(ConfigError::new(
+            format!(
+                "Enum '{}' has kind {}, but '{}' has kind {}",
+                e.name, e.kind, parent.name, parent.kind
+            ),
+            &join_keys(&e.keys, "kind"),
+        ));
+    }
+    if e.is_value_type_explicit && e.value_type != parent.value_type {
+        errors.push(ConfigError::new(
+            format!(
+                "Enum '{}' has value type {}, but '{}' has value type {}",
+                e.name, e.value_type, parent.name, parent.value_type
+            ),
+            &join_keys(&e.keys, "value_type"),
+        ));
+    }
+    if !errors.is_empty() {
+        return errors;
+    }
+    e.kind = parent.kind;
+    e.value_type = parent.value_type;
+    let is_inheriting_fields = e.fields.is_empty();
+    if e.default.is_none() {
+        // a subset only keeps the default if it is one of its items
+        e.default = parent.default.clone().filter(|default| {
+            relation == EnumRelation::Extends || e.items.iter().any(|item| item.name == *default)
         });
     }
+    if is_inheriting_fields {
+        e.fields = parent.fields.clone();
+    }
+    let inherit = |parent_item: &EnumItem| {
+        let mut item = parent_item.clone();
+        item.is_value_explicit = true;
+        if !is_inheriting_fields {
+            item.data.clear();
+        }
+        item
+    };
+
+    match relation {
+        EnumRelation::Extends => {
+            // values the parent retired stay retired in every enum extending it
+            e.reserved.extend(parent.reserved.iter().cloned());
+            e.reserved_names
+                .extend(parent.reserved_names.iter().cloned());
+            for item in e.items.iter() {
+                if parent.items.iter().any(|p| p.name == item.name) {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Item '{}.{}' is already inherited from '{}'",
+                            e.name, item.name, parent.name
+                        ),
+                        &item.keys,
+                    ));
+                } else if item.is_value_explicit
+                    && parent.items.iter().any(|p| p.value == item.value)
+                {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Item '{}.{}' reuses value {} of '{}'",
+                            e.name, item.name, item.value, parent.name
+                        ),
+                        &item.keys,
+                    ));
+                }
+            }
+            if !errors.is_empty() {
+                return errors;
+            }
+            let mut items: Vec<EnumItem> = parent.items.iter().map(inherit).collect();
+            items.append(&mut e.items);
+            e.items = items;
+            if let Err(error) = e.assign_auto_values() {
+                return vec![error];
+            }
+        }
+        EnumRelation::SubsetOf => {
+            for item in e.items.iter_mut() {
+                let Some(parent_item) = parent.items.iter().find(|p| p.name == item.name) else {
+                    let names: Vec<&str> = parent.items.iter().map(|p| p.name.as_str()).collect();
+                    let mut error = ConfigError::new(
+                        format!(
+                            "Item '{}.{}' is not an item of '{}'",
+                            e.name, item.name, parent.name
+                        ),
+                        &item.keys,
+                    );
+                    error.hint = get_suggestion_hint(item.name.as_str(), &names);
+                    errors.push(error);
+                    continue;
+                };
+                if item.is_value_explicit && item.value != parent_item.value {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Item '{}.{}' has value {}, but '{}' uses {}",
+                            e.name, item.name, item.value, parent.name, parent_item.value
+                        ),
+                        &item.keys,
+                    ));
+                    continue;
+                }
+                if item.string_value.is_some() && item.string_value != parent_item.string_value {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Item '{}.{}' has value {}, but '{}' uses {}",
+                            e.name,
+                            item.name,
+                            item.get_luau_value(),
+                            parent.name,
+                            parent_item.get_luau_value()
+                        ),
+                        &item.keys,
+                    ));
+                    continue;
+                }
+                let subset_item = std::mem::replace(item, inherit(parent_item));
+                item.merge_metadata(subset_item);
+            }
+            if !errors.is_empty() {
+                return errors;
+            }
+        }
+    }
+    e.check_values()
+}
+
+fn resolve_parents(enums: &mut [Enum], errors: &mut Vec<ConfigError>) {
+    let mut is_resolved: Vec<bool> = enums.iter().map(|e| e.parent.is_none()).collect();
+    while is_resolved.contains(&false) {
+        let mut has_progress = false;
+        for i in 0..enums.len() {
+            let Some(parent) = enums[i].parent.clone().filter(|_| !is_resolved[i]) else {
+                continue;
+            };
+            let Some(parent_index) = enums.iter().position(|e| e.name == parent.name) else {
+                let names: Vec<&str> = enums.iter().map(|e| e.name.as_str()).collect();
+                let mut error = ConfigError::new(
+                    format!(
+                        "Enum '{}' refers to unknown enum '{}'",
+                        enums[i].name, parent.name
+                    ),
+                    &join_keys(&enums[i].keys, parent.relation.get_key()),
+                );
+                error.hint = get_suggestion_hint(parent.name.as_str(), &names);
+                errors.push(error);
+                is_resolved[i] = true;
+                has_progress = true;
+                continue;
+            };
+            if !is_resolved[parent_index] {
+                continue;
+            }
+            let parent_enum = enums[parent_index].clone();
+            errors.extend(resolve_parent(&mut enums[i], &parent_enum, parent.relation));
+            is_resolved[i] = true;
+            has_progress = true;
+        }
+        if !has_progress {
+            let unresolved: Vec<&Enum> = enums
+                .iter()
+                .zip(is_resolved.iter())
+                .filter(|(_, is_resolved)| !**is_resolved)
+                .map(|(e, _)| e)
+                .collect();
+            let names: Vec<&str> = unresolved.iter().map(|e| e.name.as_str()).collect();
+            let relation = unresolved[0]
+                .parent
+                .as_ref()
+                .map_or("", |p| p.relation.get_key());
+            errors.push(ConfigError::new(
+                format!(
+                    "Enums {} extend or subset each other in a cycle",
+                    names.join(", ")
+                ),
+                &join_keys(&unresolved[0].keys, relation),
+            ));
+            return;
+        }
+    }
+}
+
+// Deprecated items are still generated, so each one is only worth a warning
+fn get_deprecation_warnings(e: &Enum) -> Vec<ConfigError> {
+    e.items
+        .iter()
+        .filter_map(|item| {
+            let reason = item.deprecated.as_ref()?;
+            let warning = ConfigError::warning(
+                format!("Item '{}.{}' is deprecated", e.name, item.name),
+                &join_keys(&item.keys, "deprecated"),
+            )
+            .at_key();
+            Some(if reason.is_empty() {
+                warning
+            } else {
+                warning.with_hint(reason.clone())
+            })
+        })
+        .collect()
+}
+
+fn parse_enum(
+    name: LuauName,
+    value: ConfigValue,
+    keys: &[ConfigKey],
+    value_type: ValueType,
+    errors: &mut Vec<ConfigError>,
+) -> Option<Enum> {
+    let mut e = Enum::new(name, Vec::new());
+    e.keys = keys.to_vec();
+    e.value_type = value_type;
+    match value {
+        ConfigValue::Table(mut table) if is_detailed_enum(&table) => {
+            let items = table.shift_remove("items")?;
+            e.items = parse_items(e.name.as_str(), items, &join_keys(keys, "items"), errors)?;
+            parse_enum_options(&mut e, table, errors);
+        }
+        value => e.items = parse_items(e.name.as_str(), value, keys, errors)?,
+    }
+    // only an extending enum gets its items from elsewhere
+    let is_extending = e
+        .parent
+        .as_ref()
+        .is_some_and(|parent| parent.relation == EnumRelation::Extends);
+    if e.items.is_empty() && !is_extending {
+        errors.push(
+            ConfigError::new(format!("Enum '{}' has no items", e.name), &e.keys)
+                .with_hint("add at least one item, e.g. `[\"City\"]`".to_string()),
+        );
+        return None;
+    }
+    // string values make a string enum without spelling out its kind
+    if e.kind == EnumKind::Standard && e.items.iter().any(|item| item.string_value.is_some()) {
+        e.kind = EnumKind::String;
+    }
+    errors.extend(get_deprecation_warnings(&e));
+    errors.extend(e.check_name());
+    errors.extend(e.check_item_names());
+    errors.extend(e.check_aliases());
+    match e.assign_auto_values() {
+        Ok(()) => errors.extend(e.check_values()),
+        Err(error) => errors.push(error),
+    }
+    Some(e)
+}
+
+// Checks the data of every item against the fields declared by its enum
+fn check_enum_data(enums: &[Enum], errors: &mut Vec<ConfigError>) {
+    for e in enums.iter() {
+        for item in e.items.iter() {
+            let data_keys = join_keys(&item.keys, "data");
+            for field in e.fields.iter() {
+                let Some(value) = item.data.get(field.name.as_str()) else {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Item '{}.{}' is missing field '{}'",
+                            e.name, item.name, field.name
+                        ),
+                        &data_keys,
+                    ));
+                    continue;
+                };
+                if let Err(error) = value.check(&field.field_type, enums) {
+                    errors.push(ConfigError::new(
+                        format!(
+                            "Item '{}.{}' has bad field '{}': {}",
+                            e.name, item.name, field.name, error
+                        ),
+                        &join_keys(&data_keys, field.name.as_str()),
+                    ));
+                }
+            }
+            for key in item.data.keys() {
+                if !e.fields.iter().any(|field| field.name.as_str() == key) {
+                    errors.push(
+                        ConfigError::new(
+                            format!(
+                                "Item '{}.{}' has data for undeclared field '{}'",
+                                e.name, item.name, key
+                            ),
+                            &join_keys(&data_keys, key.as_str()),
+                        )
+                        .at_key(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+// A table under `[enums]` that only holds other enums, such as `Tool` in `Tool.ClassId = [...]`
+pub fn is_namespace(table: &ConfigTable) -> bool {
+    !table.is_empty()
+        && !is_detailed_enum(table)
+        && table.values().all(|value| match value {
+            ConfigValue::Array(_) => true,
+            ConfigValue::Table(table) => !table.contains_key("value"),
+            _ => false,
+        })
+}
+
+fn parse_enum_tree(
+    namespace: &[LuauName],
+    table: ConfigTable,
+    keys: &[ConfigKey],
+    value_type: ValueType,
+    enums: &mut Vec<Enum>,
+    errors: &mut Vec<ConfigError>,
+) {
+    for (key, value) in table {
+        let enum_keys = join_keys(keys, key.as_str());
+        let mut path = namespace.to_vec();
+        match LuauName::parse_path(&key) {
+            Ok(segments) => path.extend(segments),
+            Err(error) => {
+                errors.push(
+                    ConfigError::new(error.to_string(), &enum_keys)
+                        .at_key()
+                        .with_hint(error.hint()),
+                );
+                continue;
+            }
+        }
+        match value {
+            ConfigValue::Table(table) if is_namespace(&table) => {
+                parse_enum_tree(&path, table, &enum_keys, value_type, enums, errors);
+            }
+            value => {
+                let full_name: Vec<&str> = path.iter().map(LuauName::as_str).collect();
+                let name = match LuauName::from_path(&full_name.join(".")) {
+                    Ok(name) => name,
+                    Err(error) => {
+                        errors.push(
+                            ConfigError::new(error.to_string(), &enum_keys)
+                                .at_key()
+                                .with_hint(error.hint()),
+                        );
+                        continue;
+                    }
+                };
+                if let Some(mut e) = parse_enum(name, value, &enum_keys, value_type, errors) {
+                    if path.len() > 1 {
+                        e.path = path;
+                    }
+                    enums.push(e);
+                }
+            }
+        }
+    }
+}
+
+// A migrations table only holds old names, so one holding only tables is a namespace
+fn parse_migrations(
+    namespace: &str,
+    table: ConfigTable,
+    keys: &[ConfigKey],
+    enums: &mut [Enum],
+    errors: &mut Vec<ConfigError>,
+) {
+    for (key, value) in table {
+        let migration_keys = join_keys(keys, key.as_str());
+        let enum_name = format!("{}{}", namespace, key);
+        let ConfigValue::Table(entries) = value else {
+            errors.push(
+                ConfigError::new(
+                    format!(
+                        "Migrations of '{}' need a table of old names or values",
+                        enum_name
+                    ),
+                    &migration_keys,
+                )
+                .with_hint("use `[migrations.MapType]` with `OldName = \"NewName\"`".to_string()),
+            );
+            continue;
+        };
+        if !entries.is_empty() && entries.values().all(|entry| entry.as_table().is_some()) {
+            parse_migrations(
+                &format!("{}.", enum_name),
+                entries,
+                &migration_keys,
+                enums,
+                errors,
+            );
+            continue;
+        }
+        let names: Vec<String> = enums.iter().map(Enum::get_full_name).collect();
+        let Some(e) = enums.iter_mut().find(|e| e.get_full_name() == enum_name) else {
+            let names: Vec<&str> = names.iter().map(String::as_str).collect();
+            let mut error = ConfigError::new(
+                format!("Migrations refer to unknown enum '{}'", enum_name),
+                &migration_keys,
+            )
+            .at_key();
+            error.hint = get_suggestion_hint(&enum_name, &names);
+            errors.push(error);
+            continue;
+        };
+        for (old, new) in entries {
+            let result = match new.as_str() {
+                Some(new) => e.add_migration(&old, new),
+                None => Err(format!(
+                    "Migration '{}' of '{}' needs the name of an item, found {}",
+                    old,
+                    enum_name,
+                    new.type_name()
+                )),
+            };
+            if let Err(message) = result {
+                errors.push(ConfigError::new(
+                    message,
+                    &join_keys(&migration_keys, old.as_str()),
+                ));
+            }
+        }
+    }
+}
+
+// Each step builds on the enums checked by the one before, so a step only runs if those passed
+fn raw_enums_to_enum_set(
+    table: ConfigTable,
+    migrations: ConfigTable,
+    value_type: ValueType,
+    naming: &NamingConfig,
+    errors: &mut Vec<ConfigError>,
+) -> Vec<Enum> {
+    let mut enums = Vec::new();
+
+    parse_enum_tree(
+        &[],
+        table,
+        &[ConfigKey::from("enums")],
+        value_type,
+        &mut enums,
+        errors,
+    );
+    if has_errors(errors) {
+        return enums;
+    }
+    resolve_parents(&mut enums, errors);
+    if has_errors(errors) {
+        return enums;
+    }
+    errors.extend(enums.iter().filter_map(Enum::check_default));
+    check_enum_data(&enums, errors);
+    parse_migrations(
+        "",
+        migrations,
+        &[ConfigKey::from("migrations")],
+        &mut enums,
+        errors,
+    );
+    if has_errors(errors) {
+        return enums;
+    }
+    if let Err(naming_errors) = naming.apply(&mut enums) {
+        errors.extend(naming_errors);
+    }
 
-    Ok(enums)
+    enums
+}
+
+// Reads a single setting of the config, reporting it if it has the wrong type
+fn parse_setting<T: DeserializeOwned>(
+    root: &ConfigTable,
+    key: &str,
+    errors: &mut Vec<ConfigError>,
+) -> Option<T> {
+    let value = root.get(key)?;
+    T::deserialize(value.clone())
+        .map_err(|error| {
+            errors.push(ConfigError::new(
+                format!("Setting '{}' is invalid: {}", key, error),
+                &[ConfigKey::from(key)],
+            ))
+        })
+        .ok()
+}
+
+fn parse_required_setting<T: DeserializeOwned + Default>(
+    root: &ConfigTable,
+    key: &str,
+    errors: &mut Vec<ConfigError>,
+) -> T {
+    if !root.contains_key(key) {
+        errors.push(ConfigError::new(
+            format!("The config is missing setting '{}'", key),
+            &[],
+        ));
+    }
+    parse_setting(root, key, errors).unwrap_or_default()
+}
+
+fn parse_table_setting(
+    root: &mut ConfigTable,
+    key: &str,
+    errors: &mut Vec<ConfigError>,
+) -> ConfigTable {
+    match root.shift_remove(key) {
+        Some(ConfigValue::Table(table)) => table,
+        Some(other) => {
+            errors.push(ConfigError::new(
+                format!(
+                    "Setting '{}' needs a table, found {}",
+                    key,
+                    other.type_name()
+                ),
+                &[ConfigKey::from(key)],
+            ));
+            ConfigTable::new()
+        }
+        None => ConfigTable::new(),
+    }
+}
+
+impl EnumSetConfig {
+    // Builds the config from a table read from any format, collecting every problem instead of
+    // stopping at the first. Warnings, such as for deprecated items, are returned with the config
+    pub fn from_table(
+        mut root: ConfigTable,
+    ) -> Result<(EnumSetConfig, Vec<ConfigError>), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        for key in root.keys() {
+            if !KNOWN_KEYS.contains(&key.as_str()) {
+                errors.push(unknown_key(key, &[], "The config", KNOWN_KEYS));
+            }
+        }
+        let use_union_types_for_export =
+            parse_required_setting(&root, "use_union_types_for_export", &mut errors);
+        let use_union_types_for_parameters =
+            parse_required_setting(&root, "use_union_types_for_parameters", &mut errors);
+        let assign_static_strings =
+            parse_required_setting(&root, "assign_static_strings", &mut errors);
+        let build_path = parse_setting(&root, "build_path", &mut errors);
+        let sort = parse_setting(&root, "sort", &mut errors).unwrap_or_default();
+        let exclude_deprecated_from_lists =
+            parse_setting(&root, "exclude_deprecated_from_lists", &mut errors).unwrap_or_default();
+        let value_type = parse_setting(&root, "value_type", &mut errors).unwrap_or_default();
+        let include = parse_setting(&root, "include", &mut errors).unwrap_or_default();
+        let naming = root
+            .get("naming")
+            .map(|naming| NamingConfig::parse(naming, &mut errors))
+            .unwrap_or_default();
+        let has_enum_table = matches!(root.get("enums"), Some(ConfigValue::Table(_)));
+        if !root.contains_key("enums") {
+            errors.push(ConfigError::new(
+                "The config is missing setting 'enums'".to_string(),
+                &[],
+            ));
+        }
+        let enums = parse_table_setting(&mut root, "enums", &mut errors);
+        if has_enum_table && enums.is_empty() {
+            errors.push(
+                ConfigError::new(
+                    "The config has no enums".to_string(),
+                    &[ConfigKey::from("enums")],
+                )
+                .at_key()
+                .with_hint("add an enum under `[enums]`, e.g. `MapType = [\"City\"]`".to_string()),
+            );
+        }
+        let migrations = parse_table_setting(&mut root, "migrations", &mut errors);
+        let enums = raw_enums_to_enum_set(enums, migrations, value_type, &naming, &mut errors);
+        if has_errors(&errors) {
+            return Err(errors);
+        }
+
+        let config = EnumSetConfig {
+            build_path,
+            use_union_types_for_export,
+            use_union_types_for_parameters,
+            assign_static_strings,
+            sort,
+            exclude_deprecated_from_lists,
+            value_type,
+            include,
+            naming,
+            enums,
+        };
+        Ok((config, errors))
+    }
+}
+
+impl TryFrom<ConfigTable> for EnumSetConfig {
+    type Error = String;
+
+    fn try_from(root: ConfigTable) -> Result<Self, Self::Error> {
+        EnumSetConfig::from_table(root)
+            .map(|(config, _)| config)
+            .map_err(|errors| join_errors(&errors))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "ConfigTable")]
 pub struct EnumSetConfig {
     pub build_path: Option<PathBuf>,
     pub use_union_types_for_export: bool,
     pub use_union_types_for_parameters: bool,
     pub assign_static_strings: bool,
-    #[serde(deserialize_with = "raw_enums_to_enum_set")]
-    pub enums: HashSet<Enum>,
+    #[serde(default)]
+    pub sort: EnumSort,
+    #[serde(default)]
+    pub exclude_deprecated_from_lists: bool,
+    // The default value type of every enum, which an enum can override with its own `value_type`
+    #[serde(default)]
+    pub value_type: ValueType,
+    // Glob patterns, relative to the config, of files whose `[enums]` are merged into this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    // The convention enum and item names are checked against or converted to
+    #[serde(default)]
+    pub naming: NamingConfig,
+    pub enums: Vec<Enum>,
+}
+
+impl EnumSetConfig {
+    pub fn get_sorted_enums(&self) -> Vec<Enum> {
+        let mut enums = self.enums.clone();
+        if self.sort == EnumSort::Alphabetical {
+            enums.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        }
+        enums
+    }
 }
 #[cfg(test)]
 pub mod config_test {
@@ -165,4 +2106,573 @@ ToolEvent = ["Primary", "Secondary"]
         let out = toml::to_string(&config).expect("Failed to serialize config");
         println!("config {}", out)
     }
+
+    #[test]
+    fn reject_invalid_names() {
+        assert!(LuauName::new("PowerLab").is_ok());
+        assert!(LuauName::new("_private2").is_ok());
+        assert_eq!(LuauName::new(""), Err(LuauNameError::Empty));
+        assert_eq!(
+            LuauName::new("1st"),
+            Err(LuauNameError::InvalidStart {
+                name: "1st".to_string(),
+                character: '1'
+            })
+        );
+        assert_eq!(
+            LuauName::new("\u{dc}mlaut"),
+            Err(LuauNameError::InvalidStart {
+                name: "\u{dc}mlaut".to_string(),
+                character: '\u{dc}'
+            })
+        );
+        assert_eq!(
+            LuauName::new("Power-Lab"),
+            Err(LuauNameError::InvalidCharacter {
+                name: "Power-Lab".to_string(),
+                character: '-'
+            })
+        );
+        for keyword in ["end", "function", "nil"] {
+            assert_eq!(
+                LuauName::new(keyword),
+                Err(LuauNameError::ReservedKeyword {
+                    name: keyword.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_item_metadata() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums.MapType]
+PowerLab = 2
+[enums.MapType.City]
+value = 1
+display = "Big City"
+description = "The main hub."
+tags = ["Urban", "Starter"]
+"#,
+        )
+        .expect("Failed to parse toml");
+        let city = &config.enums[0].items[0];
+        assert_eq!(city.name.as_str(), "City");
+        assert_eq!(city.value, 1);
+        assert_eq!(city.display.as_deref(), Some("Big City"));
+        assert_eq!(city.description.as_deref(), Some("The main hub."));
+        assert_eq!(city.tags, vec!["Urban", "Starter"]);
+        assert!(!config.enums[0].items[1].has_metadata());
+    }
+
+    pub const PAYLOAD_STR: &str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+EquipContext = ["Right", "Left", "Dual"]
+[enums.ToolClassId]
+fields = { Damage = "number", Icon = "string", TwoHanded = "boolean", Tint = "Color3", Offset = "Vector3", Context = "EquipContext" }
+[enums.ToolClassId.items.BladeCutlass]
+value = 1
+data = { Damage = 12.5, Icon = "rbxassetid://1", TwoHanded = false, Tint = [255, 0, 0], Offset = [0, 1, 0], Context = "Right" }
+"#;
+
+    #[test]
+    fn parse_item_data() {
+        let config: EnumSetConfig = toml::from_str(PAYLOAD_STR).expect("Failed to parse toml");
+        let tool = &config.enums[1];
+        assert_eq!(tool.fields.len(), 6);
+        assert_eq!(
+            tool.fields[5].field_type,
+            FieldType::Enum(LuauName::new("EquipContext").unwrap())
+        );
+        assert_eq!(
+            tool.items[0].data.get("Context"),
+            Some(&FieldValue::Text("Right".to_string()))
+        );
+    }
+
+    #[test]
+    fn reject_bad_item_data() {
+        let missing = PAYLOAD_STR.replace("TwoHanded = false, ", "");
+        let error = toml::from_str::<EnumSetConfig>(&missing).expect_err("Missing field passed");
+        assert!(error
+            .message()
+            .contains("Item 'ToolClassId.BladeCutlass' is missing field 'TwoHanded'"));
+
+        let mistyped = PAYLOAD_STR.replace("Context = \"Right\"", "Context = \"Up\"");
+        let error = toml::from_str::<EnumSetConfig>(&mistyped).expect_err("Bad enum item passed");
+        assert!(error
+            .message()
+            .contains("has bad field 'Context': 'Up' is not an item of 'EquipContext'"));
+
+        let shadowing = PAYLOAD_STR.replace("EquipContext = [", "Vector3 = [");
+        let error =
+            toml::from_str::<EnumSetConfig>(&shadowing).expect_err("Built-in type name passed");
+        assert!(error
+            .message()
+            .contains("Enum 'Vector3' has the name of a built-in field type"));
+
+        let infinite = PAYLOAD_STR.replace("Damage = 12.5", "Damage = inf");
+        let error = toml::from_str::<EnumSetConfig>(&infinite).expect_err("Infinite number passed");
+        assert!(error.message().contains(
+            "Item 'ToolClassId.BladeCutlass' has unsupported data 'inf' for field 'Damage'"
+        ));
+    }
+
+    #[test]
+    fn parse_flags() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums.Permission]
+kind = "flags"
+items = ["Read", "Write", "Admin"]
+"#,
+        )
+        .expect("Failed to parse toml");
+        let values: Vec<i64> = config.enums[0]
+            .items
+            .iter()
+            .map(|item| item.value)
+            .collect();
+        assert_eq!(values, vec![1, 2, 4]);
+
+        let error = toml::from_str::<EnumSetConfig>(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums.Permission]
+kind = "flags"
+items = { Read = 1, Write = 3 }
+"#,
+        )
+        .expect_err("Multi bit flag passed");
+        assert!(error
+            .message()
+            .contains("Flag 'Permission.Write' has value 3, which is not a single bit"));
+    }
+
+    #[test]
+    fn check_value_types() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+value_type = "u32"
+[enums]
+ErrorCode = { value_type = "i32", items = { Unknown = -1, Timeout = 70000 } }
+[enums.Region]
+Europe = 70000
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        assert_eq!(config.enums[0].value_type, ValueType::I32);
+        assert_eq!(config.enums[0].items[0].value, -1);
+        assert_eq!(config.enums[1].value_type, ValueType::U32);
+        assert_eq!(config.enums[1].items[0].value, 70000);
+
+        let error =
+            toml::from_str::<EnumSetConfig>(&config_str.replace("value_type = \"u32\"\n", ""))
+                .expect_err("Truncated value passed");
+        assert!(error
+            .message()
+            .contains("Item 'Region.Europe' has value 70000, which does not fit in u16"));
+
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace("\"i32\"", "\"u8\""))
+            .expect_err("Negative value passed");
+        assert!(error
+            .message()
+            .contains("Item 'ErrorCode.Unknown' has value -1, which does not fit in u8"));
+
+        let items: Vec<String> = (0..256).map(|i| format!("\"Item{}\"", i)).collect();
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace(
+            "[enums.Region]",
+            &format!(
+                "Many = {{ value_type = \"u8\", items = [{}] }}\n[enums.Region]",
+                items.join(", ")
+            ),
+        ))
+        .expect_err("Overflowing auto value passed");
+        assert!(error
+            .message()
+            .contains("Enum 'Many' has no values left to assign to 'Item255'"));
+    }
+
+    #[test]
+    fn number_items() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+Letter = ["A", { name = "B", value = 10, display = "Bee" }, "C"]
+Slot = { start = 0, items = ["Head", "Body"] }
+Weapon = { start = 100, step = 10, items = ["Sword", "Bow", { name = "Staff", value = 110 }, "Axe"] }
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let get_items = |i: usize| -> Vec<(String, i64)> {
+            config.enums[i]
+                .items
+                .iter()
+                .map(|item| (item.name.to_string(), item.value))
+                .collect()
+        };
+        assert_eq!(
+            get_items(0),
+            vec![
+                ("A".to_string(), 1),
+                ("B".to_string(), 10),
+                ("C".to_string(), 11)
+            ]
+        );
+        assert_eq!(config.enums[0].items[1].display.as_deref(), Some("Bee"));
+        assert_eq!(
+            get_items(1),
+            vec![("Head".to_string(), 0), ("Body".to_string(), 1)]
+        );
+        assert_eq!(
+            get_items(2),
+            vec![
+                ("Sword".to_string(), 100),
+                ("Bow".to_string(), 120),
+                ("Staff".to_string(), 110),
+                ("Axe".to_string(), 130)
+            ]
+        );
+
+        let error = toml::from_str::<EnumSetConfig>(
+            &config_str.replace("{ start = 0,", "{ kind = \"flags\", start = 0,"),
+        )
+        .expect_err("Flag start passed");
+        assert!(error
+            .message()
+            .contains("Enum 'Slot' sets a start or step, which only apply to standard enums"));
+    }
+
+    #[test]
+    fn resolve_defaults() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+MapType = { default = "City", items = ["City", "PowerLab"] }
+BigMapType = { extends = "MapType", items = ["Desert"] }
+LabMapType = { subset_of = "MapType", items = ["PowerLab"] }
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let defaults: Vec<Option<&str>> = config
+            .enums
+            .iter()
+            .map(|e| e.default.as_ref().map(LuauName::as_str))
+            .collect();
+        assert_eq!(defaults, vec![Some("City"), Some("City"), None]);
+
+        let error = toml::from_str::<EnumSetConfig>(
+            &config_str.replace("\"City\", items", "\"Town\", items"),
+        )
+        .expect_err("Unknown default passed");
+        assert!(error
+            .message()
+            .contains("Default 'Town' of 'MapType' is not one of its items"));
+    }
+
+    #[test]
+    fn parse_migrations() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+MapType = ["City", "PowerLab"]
+Tool.Event = { kind = "string", items = ["Equipped"] }
+
+[migrations.MapType]
+Town = "City"
+3 = "PowerLab"
+
+[migrations.Tool.Event]
+equipped_v1 = "Equipped"
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        assert_eq!(
+            config.enums[0].migrations,
+            vec![
+                EnumMigration {
+                    from: MigrationKey::Name("Town".to_string()),
+                    to: LuauName::new("City").unwrap(),
+                },
+                EnumMigration {
+                    from: MigrationKey::Value(3),
+                    to: LuauName::new("PowerLab").unwrap(),
+                },
+            ]
+        );
+        assert_eq!(config.enums[1].migrations.len(), 1);
+
+        let error =
+            toml::from_str::<EnumSetConfig>(&config_str.replace("= \"City\"", "= \"Town\""))
+                .expect_err("Unknown migration target passed");
+        assert!(error
+            .message()
+            .contains("Migration 'Town' of 'MapType' refers to unknown item 'Town'"));
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace("Town =", "City ="))
+            .expect_err("Migration of a current item passed");
+        assert!(error
+            .message()
+            .contains("Migration 'City' of 'MapType' renames an item that still exists"));
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace("3 =", "2 ="))
+            .expect_err("Migration of a current value passed");
+        assert!(error.message().contains(
+            "Migration '2' of 'MapType' moves the value of 'PowerLab', which still exists"
+        ));
+    }
+
+    #[test]
+    fn check_reserved_values() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+MapType = { reserved = [2, "4..5"], reserved_names = ["Forest"], items = ["City", "PowerLab", "Desert", "Ocean"] }
+BigMapType = { extends = "MapType", items = ["Moon"] }
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let values: Vec<i64> = config.enums[1]
+            .items
+            .iter()
+            .map(|item| item.value)
+            .collect();
+        assert_eq!(values, vec![1, 3, 6, 7, 8]);
+
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace("\"Ocean\"", "\"Forest\""))
+            .expect_err("Reserved name passed");
+        assert!(error
+            .message()
+            .contains("Item 'MapType.Forest' uses reserved name 'Forest'"));
+        let error = toml::from_str::<EnumSetConfig>(
+            &config_str.replace("items = [\"Moon\"]", "items = { Moon = 5 }"),
+        )
+        .expect_err("Reserved value passed");
+        assert!(error
+            .message()
+            .contains("Item 'BigMapType.Moon' uses reserved value 5"));
+    }
+
+    #[test]
+    fn parse_string_values() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+ProductSku = { kind = "string", items = ["GoldPack", "GemPack"] }
+[enums.AnalyticsEvent]
+Purchase = "purchase_v2"
+Refund = { value = "refund", deprecated = true }
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let get_values = |i: usize| -> Vec<Option<String>> {
+            config.enums[i]
+                .items
+                .iter()
+                .map(|item| item.string_value.clone())
+                .collect()
+        };
+        assert_eq!(config.enums[1].kind, EnumKind::String);
+        assert_eq!(
+            get_values(0),
+            vec![Some("GoldPack".to_string()), Some("GemPack".to_string())]
+        );
+        assert_eq!(
+            get_values(1),
+            vec![Some("purchase_v2".to_string()), Some("refund".to_string())]
+        );
+
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace("\"refund\"", "2"))
+            .expect_err("Mixed values passed");
+        assert!(error.message().contains(
+            "Item 'AnalyticsEvent.Refund' needs a string value, as 'AnalyticsEvent' is a string enum"
+        ));
+
+        let error =
+            toml::from_str::<EnumSetConfig>(&config_str.replace("\"refund\"", "\"purchase_v2\""))
+                .expect_err("Duplicate string value passed");
+        assert!(error.message().contains(
+            "Items 'AnalyticsEvent.Purchase' and 'AnalyticsEvent.Refund' share value \"purchase_v2\""
+        ));
+    }
+
+    #[test]
+    fn parse_aliases() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums.MapType]
+City = 1
+Laboratory = { value = 2, aliases = ["PowerLab"] }
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        assert_eq!(
+            config.enums[0].items[1].aliases,
+            vec![LuauName::new("PowerLab").unwrap()]
+        );
+
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace("PowerLab", "City"))
+            .expect_err("Alias clash passed");
+        assert!(error.message().contains(
+            "Alias 'City' of 'MapType.Laboratory' is already used by another item or alias"
+        ));
+    }
+
+    #[test]
+    fn resolve_composed_enums() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+MainHand = { subset_of = "EquipContext", items = ["Left", "Right"] }
+DualEquipContext = { extends = "EquipContext", items = ["Dual"] }
+EquipContext = ["Right", "Left"]
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let get_items = |i: usize| -> Vec<(String, i64)> {
+            config.enums[i]
+                .items
+                .iter()
+                .map(|item| (item.name.to_string(), item.value))
+                .collect()
+        };
+        assert_eq!(
+            get_items(0),
+            vec![("Left".to_string(), 2), ("Right".to_string(), 1)]
+        );
+        assert_eq!(
+            get_items(1),
+            vec![
+                ("Right".to_string(), 1),
+                ("Left".to_string(), 2),
+                ("Dual".to_string(), 3)
+            ]
+        );
+
+        let error =
+            toml::from_str::<EnumSetConfig>(&config_str.replace("\"Left\", \"Right\"]", "\"Up\"]"))
+                .expect_err("Unknown subset item passed");
+        assert!(error
+            .message()
+            .contains("Item 'MainHand.Up' is not an item of 'EquipContext'"));
+
+        let error = toml::from_str::<EnumSetConfig>(
+            &config_str
+                .replace(
+                    "EquipContext = [",
+                    "EquipContext = { extends = \"DualEquipContext\", items = [",
+                )
+                .replace("\"Left\"]\n", "\"Left\"] }\n"),
+        )
+        .expect_err("Cycle passed");
+        assert!(error.message().contains("in a cycle"));
+
+        let error = toml::from_str::<EnumSetConfig>(&config_str.replace(
+            "items = [\"Dual\"]",
+            "kind = \"flags\", value_type = \"u16\", items = [\"Dual\"]",
+        ))
+        .expect_err("Conflicting kind passed");
+        assert_eq!(
+            error.message().trim(),
+            "Enum 'DualEquipContext' has kind flags, but 'EquipContext' has kind standard"
+        );
+    }
+
+    #[test]
+    fn keep_subset_metadata() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+EquipContext = { items = [{ name = "Right", display = "Right Hand", tags = ["Hand"] }, "Left"] }
+MainHand = { subset_of = "EquipContext", items = [{ name = "Right", display = "Main Hand" }] }
+"#,
+        )
+        .expect("Failed to parse toml");
+        let item = &config.enums[1].items[0];
+        assert_eq!(item.value, 1);
+        assert_eq!(item.display.as_deref(), Some("Main Hand"));
+        assert_eq!(item.tags, vec!["Hand".to_string()]);
+    }
+
+    #[test]
+    fn parse_namespaces() {
+        let config: EnumSetConfig = toml::from_str(
+            r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+[enums]
+"Tool.Event" = ["Equipped"]
+MapType = ["City"]
+
+[enums.Tool.ClassId]
+BladeCutlass = 1
+"#,
+        )
+        .expect("Failed to parse toml");
+        let names: Vec<(String, String)> = config
+            .enums
+            .iter()
+            .map(|e| (e.name.to_string(), e.get_full_name()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("ToolEvent".to_string(), "Tool.Event".to_string()),
+                ("MapType".to_string(), "MapType".to_string()),
+                ("ToolClassId".to_string(), "Tool.ClassId".to_string()),
+            ]
+        );
+        assert!(config.enums[1].path.is_empty());
+    }
+
+    #[test]
+    fn preserve_declaration_order() {
+        let config: EnumSetConfig = toml::from_str(TOML_STR).expect("Failed to parse toml");
+        let names: Vec<&str> = config.enums.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "MapType",
+                "RunMode",
+                "EffectClassId",
+                "MoveClassId",
+                "EquipContext",
+                "ToolClassId",
+                "ToolEvent"
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_alphabetical() {
+        let config: EnumSetConfig =
+            toml::from_str(&format!("sort = \"alphabetical\"\n{}", TOML_STR))
+                .expect("Failed to parse toml");
+        let sorted = config.get_sorted_enums();
+        let names: Vec<&str> = sorted.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names.first(), Some(&"EffectClassId"));
+        assert_eq!(names.last(), Some(&"ToolEvent"));
+    }
 }
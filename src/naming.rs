@@ -0,0 +1,426 @@
+use crate::diagnostic::{get_candidates_hint, join_keys, unknown_key, ConfigError, ConfigKey};
+use crate::field::{FieldType, FieldValue};
+use crate::format::ConfigValue;
+use crate::model::{Enum, EnumKind, LuauName};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const NAMING_CASES: &[&str] = &[
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+];
+pub const NAMING_KEYS: &[&str] = &["items", "enums", "convert", "keep_original"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NamingCase {
+    PascalCase,
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    #[serde(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+}
+
+impl fmt::Display for NamingCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NamingCase::PascalCase => "PascalCase",
+            NamingCase::CamelCase => "camelCase",
+            NamingCase::SnakeCase => "snake_case",
+            NamingCase::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+// Splits `power_lab`, `PowerLab`, `POWER_LAB` and `HTTPServer` into lowercase words
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            let is_boundary = i > 0 && c.is_ascii_uppercase() && {
+                let previous = chars[i - 1];
+                previous.is_ascii_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_ascii_uppercase()
+                        && chars
+                            .get(i + 1)
+                            .is_some_and(|next| next.is_ascii_lowercase()))
+            };
+            if is_boundary {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(c.to_ascii_lowercase());
+        }
+        words.push(word);
+    }
+    words
+}
+
+impl NamingCase {
+    pub const ALL: &'static [NamingCase] = &[
+        NamingCase::PascalCase,
+        NamingCase::CamelCase,
+        NamingCase::SnakeCase,
+        NamingCase::ScreamingSnakeCase,
+    ];
+
+    pub fn convert(&self, name: &str) -> String {
+        let words = split_words(name);
+        if words.is_empty() {
+            return name.to_string();
+        }
+        match self {
+            NamingCase::PascalCase => words.iter().map(|word| capitalize(word)).collect(),
+            NamingCase::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.clone()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            NamingCase::SnakeCase => words.join("_"),
+            NamingCase::ScreamingSnakeCase => words.join("_").to_ascii_uppercase(),
+        }
+    }
+
+    fn rename(&self, name: &LuauName) -> Result<LuauName, String> {
+        LuauName::new(&self.convert(name.as_str())).map_err(|error| error.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NamingConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<NamingCase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enums: Option<NamingCase>,
+    // Converts names that break the convention instead of rejecting them
+    #[serde(default)]
+    pub convert: bool,
+    // Converted items keep their original spelling as an alias, or as the value of string enums
+    #[serde(default)]
+    pub keep_original: bool,
+}
+
+impl NamingConfig {
+    // Reads the `naming` setting, reporting every unknown key or bad option
+    pub fn parse(value: &ConfigValue, errors: &mut Vec<ConfigError>) -> NamingConfig {
+        let keys = [ConfigKey::from("naming")];
+        let mut naming = NamingConfig::default();
+        let Some(table) = value.as_table() else {
+            errors.push(ConfigError::new(
+                format!(
+                    "Setting 'naming' needs a table, found {}",
+                    value.type_name()
+                ),
+                &keys,
+            ));
+            return naming;
+        };
+        for (key, value) in table {
+            let option_keys = join_keys(&keys, key.as_str());
+            match key.as_str() {
+                "items" | "enums" => match NamingCase::deserialize(value.clone()) {
+                    Ok(case) if key == "items" => naming.items = Some(case),
+                    Ok(case) => naming.enums = Some(case),
+                    Err(_) => errors.push(
+                        ConfigError::new(
+                            format!("Unknown naming convention {} for {}", value, key),
+                            &option_keys,
+                        )
+                        .with_hint(get_candidates_hint(
+                            value.as_str().unwrap_or_default(),
+                            NAMING_CASES,
+                        )),
+                    ),
+                },
+                "convert" | "keep_original" => match value {
+                    ConfigValue::Boolean(flag) if key == "convert" => naming.convert = *flag,
+                    ConfigValue::Boolean(flag) => naming.keep_original = *flag,
+                    _ => errors.push(ConfigError::new(
+                        format!(
+                            "Naming option '{}' needs a boolean, found {}",
+                            key,
+                            value.type_name()
+                        ),
+                        &option_keys,
+                    )),
+                },
+                _ => errors.push(unknown_key(key, &keys, "Setting 'naming'", NAMING_KEYS)),
+            }
+        }
+        naming
+    }
+
+    // Checks or converts every enum and item name, along with the names referring to them
+    pub fn apply(&self, enums: &mut [Enum]) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        if let Some(case) = self.enums {
+            self.apply_to_enums(case, enums, &mut errors);
+        }
+        if let Some(case) = self.items {
+            self.apply_to_items(case, enums, &mut errors);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        for (index, e) in enums.iter().enumerate() {
+            if let Some(other) = enums[..index]
+                .iter()
+                .find(|other| other.get_full_name() == e.get_full_name())
+            {
+                errors.push(
+                    ConfigError::new(
+                        format!(
+                            "Enums '{}' and '{}' are both named '{}' after conversion",
+                            other
+                                .previous_name
+                                .as_ref()
+                                .unwrap_or(&other.get_full_name()),
+                            e.previous_name.as_ref().unwrap_or(&e.get_full_name()),
+                            e.get_full_name()
+                        ),
+                        &e.keys,
+                    )
+                    .at_key(),
+                );
+            }
+            errors.extend(e.check_name());
+            for (index, item) in e.items.iter().enumerate() {
+                if e.items[..index].iter().any(|other| other.name == item.name) {
+                    errors.push(
+                        ConfigError::new(
+                            format!(
+                                "Enum '{}' has more than one item named '{}' after conversion",
+                                e.name, item.name
+                            ),
+                            &item.keys,
+                        )
+                        .at_key(),
+                    );
+                }
+            }
+            errors.extend(e.check_aliases());
+            errors.extend(e.check_values());
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn apply_to_enums(&self, case: NamingCase, enums: &mut [Enum], errors: &mut Vec<ConfigError>) {
+        let mut renamed: Vec<(LuauName, LuauName)> = Vec::new();
+        for e in enums.iter_mut() {
+            let path = e.get_path();
+            let converted = match path
+                .iter()
+                .map(|segment| case.rename(segment))
+                .collect::<Result<Vec<LuauName>, String>>()
+            {
+                Ok(converted) => converted,
+                Err(message) => {
+                    errors.push(ConfigError::new(message, &e.keys).at_key());
+                    continue;
+                }
+            };
+            if converted == path {
+                continue;
+            }
+            let segments: Vec<&str> = converted.iter().map(LuauName::as_str).collect();
+            if !self.convert {
+                errors.push(
+                    ConfigError::new(
+                        format!(
+                            "Enum '{}' is not {}, name it '{}'",
+                            e.get_full_name(),
+                            case,
+                            segments.join(".")
+                        ),
+                        &e.keys,
+                    )
+                    .at_key(),
+                );
+                continue;
+            }
+            let name = match LuauName::new(&segments.concat()) {
+                Ok(name) => name,
+                Err(error) => {
+                    errors.push(ConfigError::new(error.to_string(), &e.keys).at_key());
+                    continue;
+                }
+            };
+            renamed.push((e.name.clone(), name.clone()));
+            e.previous_name = Some(e.get_full_name());
+            e.name = name;
+            if !e.path.is_empty() {
+                e.path = converted;
+            }
+        }
+
+        let rename = |name: &mut LuauName| {
+            if let Some((_, new)) = renamed.iter().find(|(old, _)| old == name) {
+                *name = new.clone();
+            }
+        };
+        for e in enums.iter_mut() {
+            if let Some(parent) = &mut e.parent {
+                rename(&mut parent.name);
+            }
+            for field in e.fields.iter_mut() {
+                if let FieldType::Enum(enum_name) = &mut field.field_type {
+                    rename(enum_name);
+                }
+            }
+        }
+    }
+
+    fn apply_to_items(&self, case: NamingCase, enums: &mut [Enum], errors: &mut Vec<ConfigError>) {
+        for e in enums.iter_mut() {
+            for item in e.items.iter_mut() {
+                let name = match case.rename(&item.name) {
+                    Ok(name) => name,
+                    Err(message) => {
+                        errors.push(ConfigError::new(message, &item.keys).at_key());
+                        continue;
+                    }
+                };
+                if name == item.name {
+                    continue;
+                }
+                if !self.convert {
+                    errors.push(
+                        ConfigError::new(
+                            format!(
+                                "Item '{}.{}' is not {}, name it '{}'",
+                                e.name, item.name, case, name
+                            ),
+                            &item.keys,
+                        )
+                        .at_key(),
+                    );
+                    continue;
+                }
+                let original = std::mem::replace(&mut item.name, name);
+                item.previous_names.push(original.clone());
+                if e.kind == EnumKind::String {
+                    // string values default to the item name
+                    if !self.keep_original
+                        && item.string_value.as_deref() == Some(original.as_str())
+                    {
+                        item.string_value = Some(item.name.to_string());
+                    }
+                } else if self.keep_original && !item.aliases.contains(&original) {
+                    item.aliases.push(original);
+                }
+            }
+            if !self.convert {
+                continue;
+            }
+
+            let mut rename = |name: &mut LuauName, keys: &[ConfigKey]| match case.rename(name) {
+                Ok(converted) => *name = converted,
+                Err(message) => errors.push(ConfigError::new(message, keys)),
+            };
+            if let Some(default) = &mut e.default {
+                rename(default, &join_keys(&e.keys, "default"));
+            }
+            for (index, reserved_name) in e.reserved_names.iter_mut().enumerate() {
+                rename(
+                    reserved_name,
+                    &join_keys(&join_keys(&e.keys, "reserved_names"), index),
+                );
+            }
+            for migration in e.migrations.iter_mut() {
+                rename(&mut migration.to, &e.keys);
+            }
+            // data naming an item of another enum must follow that item
+            let enum_fields: Vec<String> = e
+                .fields
+                .iter()
+                .filter(|field| matches!(field.field_type, FieldType::Enum(_)))
+                .map(|field| field.name.to_string())
+                .collect();
+            for item in e.items.iter_mut() {
+                for field_name in enum_fields.iter() {
+                    if let Some(FieldValue::Text(item_name)) = item.data.get_mut(field_name) {
+                        *item_name = case.convert(item_name);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod naming_test {
+    use super::*;
+    use crate::model::EnumSetConfig;
+
+    #[test]
+    fn convert_names() {
+        for name in ["power_lab", "PowerLab", "POWER_LAB", "powerLab"] {
+            assert_eq!(NamingCase::PascalCase.convert(name), "PowerLab");
+            assert_eq!(NamingCase::CamelCase.convert(name), "powerLab");
+            assert_eq!(NamingCase::SnakeCase.convert(name), "power_lab");
+            assert_eq!(NamingCase::ScreamingSnakeCase.convert(name), "POWER_LAB");
+        }
+        assert_eq!(NamingCase::PascalCase.convert("HTTPServer"), "HttpServer");
+        assert_eq!(NamingCase::SnakeCase.convert("Level2Boss"), "level2_boss");
+    }
+
+    #[test]
+    fn apply_naming() {
+        let config_str = r#"
+use_union_types_for_export = true
+use_union_types_for_parameters = true
+assign_static_strings = true
+naming = { items = "PascalCase", enums = "PascalCase", convert = true, keep_original = true }
+[enums]
+map_type = { default = "power_lab", items = ["City", "power_lab"] }
+ANALYTICS_EVENT = { kind = "string", items = ["purchase"] }
+"#;
+        let config: EnumSetConfig = toml::from_str(config_str).expect("Failed to parse toml");
+        let map_type = &config.enums[0];
+        assert_eq!(map_type.name.as_str(), "MapType");
+        assert_eq!(map_type.items[1].name.as_str(), "PowerLab");
+        assert_eq!(map_type.items[1].aliases[0].as_str(), "power_lab");
+        assert_eq!(
+            map_type.default.as_ref().map(LuauName::as_str),
+            Some("PowerLab")
+        );
+        let analytics_event = &config.enums[1];
+        assert_eq!(analytics_event.name.as_str(), "AnalyticsEvent");
+        assert_eq!(
+            analytics_event.items[0].string_value.as_deref(),
+            Some("purchase")
+        );
+
+        let error = toml::from_str::<EnumSetConfig>(
+            &config_str.replace("convert = true", "convert = false"),
+        )
+        .expect_err("Bad names passed");
+        assert!(error
+            .message()
+            .contains("Enum 'map_type' is not PascalCase, name it 'MapType'"));
+    }
+}
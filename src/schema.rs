@@ -0,0 +1,291 @@
+use crate::field::FieldType;
+use crate::model::{EnumKind, EnumSort, ValueType, LUAU_KEYWORDS};
+use crate::naming::NamingCase;
+use serde_json::{json, Value};
+
+pub const LUAU_NAME_PATTERN: &str = "^[A-Za-z_][A-Za-z0-9_]*$";
+pub const ENUM_NAME_PATTERN: &str = "^[A-Za-z_][A-Za-z0-9_]*(\\.[A-Za-z_][A-Za-z0-9_]*)*$";
+pub const VALUE_RANGE_PATTERN: &str = "^\\s*-?[0-9]+\\s*\\.\\.\\s*-?[0-9]+\\s*$";
+pub const LOCALE_ID_PATTERN: &str = "^[a-z]{2,3}(-[a-z0-9]+)*$";
+
+fn get_config_properties() -> Value {
+    json!({
+        "build_path": {
+            "type": "string",
+            "description": "Where `build` writes the generated script."
+        },
+        "use_union_types_for_export": {
+            "type": "boolean",
+            "description": "Export each enum as a union of string literals."
+        },
+        "use_union_types_for_parameters": {
+            "type": "boolean",
+            "description": "Type the helper functions as per-enum overloads."
+        },
+        "assign_static_strings": {
+            "type": "boolean",
+            "description": "Type each dictionary entry as its own string literal."
+        },
+        "sort": {
+            "enum": EnumSort::ALL,
+            "default": EnumSort::default(),
+            "description": "The order enums are generated in."
+        },
+        "exclude_deprecated_from_lists": {
+            "type": "boolean",
+            "default": false,
+            "description": "Leave deprecated items out of `getEnumItems`."
+        },
+        "value_type": { "$ref": "#/definitions/valueType" },
+        "include": {
+            "type": "array",
+            "items": { "type": "string" },
+            "description": "Glob patterns of files whose enums are merged into this config."
+        },
+        "enums": { "$ref": "#/definitions/enumTree" },
+        "migrations": { "$ref": "#/definitions/migrationTree" },
+        "naming": {
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "items": { "$ref": "#/definitions/namingCase" },
+                "enums": { "$ref": "#/definitions/namingCase" },
+                "convert": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Convert names that break the convention instead of rejecting them."
+                },
+                "keep_original": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Keep the original spelling of converted items as an alias."
+                }
+            }
+        }
+    })
+}
+
+fn get_item_properties() -> Value {
+    json!({
+        "value": { "$ref": "#/definitions/value" },
+        "display": { "type": "string" },
+        "description": { "type": "string" },
+        "tags": { "type": "array", "items": { "type": "string" } },
+        "data": { "type": "object" },
+        "aliases": { "type": "array", "items": { "$ref": "#/definitions/luauName" } },
+        "deprecated": {
+            "type": ["string", "boolean"],
+            "description": "Why the item is deprecated, or `true`."
+        },
+        "text": {
+            "type": "object",
+            "propertyNames": { "pattern": LOCALE_ID_PATTERN },
+            "additionalProperties": { "type": "string" },
+            "description": "Localized display text keyed by Roblox locale id."
+        }
+    })
+}
+
+// Items written as tables inside an array carry their name, and may leave out the value
+fn get_entry_properties() -> Value {
+    let mut properties = get_item_properties();
+    properties["name"] = json!({ "$ref": "#/definitions/luauName" });
+    properties
+}
+
+fn get_enum_properties() -> Value {
+    json!({
+        "items": {
+            "anyOf": [
+                { "$ref": "#/definitions/itemList" },
+                { "$ref": "#/definitions/itemTable" }
+            ]
+        },
+        "fields": {
+            "type": "object",
+            "propertyNames": { "$ref": "#/definitions/luauName" },
+            "additionalProperties": { "$ref": "#/definitions/fieldType" }
+        },
+        "kind": { "enum": EnumKind::ALL, "default": EnumKind::default() },
+        "value_type": { "$ref": "#/definitions/valueType" },
+        "start": {
+            "type": "integer",
+            "default": 1,
+            "description": "The value of the first automatically numbered item."
+        },
+        "step": {
+            "type": "integer",
+            "minimum": 1,
+            "default": 1,
+            "description": "How far apart automatically numbered items are."
+        },
+        "default": {
+            "$ref": "#/definitions/luauName",
+            "description": "The item `getDefault` and the `OrDefault` helpers fall back to."
+        },
+        "reserved": {
+            "type": "array",
+            "items": {
+                "anyOf": [
+                    { "type": "integer" },
+                    { "type": "string", "pattern": VALUE_RANGE_PATTERN }
+                ]
+            },
+            "description": "Values of removed items, as integers or ranges like \"7..9\"."
+        },
+        "reserved_names": {
+            "type": "array",
+            "items": { "$ref": "#/definitions/luauName" },
+            "description": "Names of removed items."
+        },
+        "extends": { "$ref": "#/definitions/enumName" },
+        "subset_of": { "$ref": "#/definitions/enumName" }
+    })
+}
+
+// A JSON Schema of the config, for editors that validate TOML against one
+pub fn get_schema() -> Value {
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "pseudo-enum config",
+        "type": "object",
+        "additionalProperties": false,
+        "required": [
+            "use_union_types_for_export",
+            "use_union_types_for_parameters",
+            "assign_static_strings"
+        ],
+        "anyOf": [{ "required": ["enums"] }, { "required": ["include"] }],
+        "properties": get_config_properties(),
+        "definitions": {
+            "luauName": {
+                "type": "string",
+                "pattern": LUAU_NAME_PATTERN,
+                "not": { "enum": LUAU_KEYWORDS }
+            },
+            "enumName": {
+                "type": "string",
+                "pattern": ENUM_NAME_PATTERN
+            },
+            "value": {
+                "anyOf": [
+                    {
+                        "type": "integer",
+                        "minimum": ValueType::I53.get_range().0,
+                        "maximum": ValueType::I53.get_range().1
+                    },
+                    { "type": "string", "description": "The value of an item of a string enum." }
+                ]
+            },
+            "valueType": {
+                "enum": ValueType::ALL,
+                "default": ValueType::default(),
+                "description": "The integer type item values must fit in."
+            },
+            "namingCase": { "enum": NamingCase::ALL },
+            "fieldType": {
+                "anyOf": [
+                    { "enum": FieldType::BUILT_IN },
+                    { "$ref": "#/definitions/enumName" }
+                ]
+            },
+            "item": {
+                "type": "object",
+                "required": ["value"],
+                "additionalProperties": false,
+                "properties": get_item_properties()
+            },
+            "itemEntry": {
+                "type": "object",
+                "required": ["name"],
+                "additionalProperties": false,
+                "properties": get_entry_properties()
+            },
+            "itemList": {
+                "type": "array",
+                "uniqueItems": true,
+                "items": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/luauName" },
+                        { "$ref": "#/definitions/itemEntry" }
+                    ]
+                }
+            },
+            "itemTable": {
+                "type": "object",
+                "propertyNames": { "$ref": "#/definitions/luauName" },
+                "additionalProperties": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/value" },
+                        { "$ref": "#/definitions/item" }
+                    ]
+                }
+            },
+            "detailedEnum": {
+                "type": "object",
+                "required": ["items"],
+                "additionalProperties": false,
+                "properties": get_enum_properties()
+            },
+            "enum": {
+                "anyOf": [
+                    { "$ref": "#/definitions/itemList" },
+                    { "$ref": "#/definitions/itemTable" },
+                    { "$ref": "#/definitions/detailedEnum" }
+                ]
+            },
+            "migrationTable": {
+                "type": "object",
+                "description": "Old names or values mapped to the items they became.",
+                "additionalProperties": { "$ref": "#/definitions/luauName" }
+            },
+            "migrationTree": {
+                "type": "object",
+                "propertyNames": { "$ref": "#/definitions/enumName" },
+                "additionalProperties": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/migrationTable" },
+                        { "$ref": "#/definitions/migrationTree" }
+                    ]
+                }
+            },
+            "enumTree": {
+                "type": "object",
+                "propertyNames": { "$ref": "#/definitions/enumName" },
+                "additionalProperties": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/enum" },
+                        { "$ref": "#/definitions/enumTree" }
+                    ]
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+pub mod schema_test {
+    use super::*;
+    use crate::diagnostic::KNOWN_KEYS;
+    use crate::model::{ENUM_KEYS, ITEM_KEYS};
+
+    fn assert_keys(properties: Value, keys: &[&str]) {
+        let mut property_keys: Vec<&str> = properties
+            .as_object()
+            .expect("Expected an object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        let mut keys = keys.to_vec();
+        property_keys.sort();
+        keys.sort();
+        assert_eq!(property_keys, keys);
+    }
+
+    #[test]
+    fn cover_every_key() {
+        assert_keys(get_config_properties(), KNOWN_KEYS);
+        assert_keys(get_enum_properties(), ENUM_KEYS);
+        assert_keys(get_item_properties(), ITEM_KEYS);
+    }
+}